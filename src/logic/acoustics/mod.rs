@@ -0,0 +1,4 @@
+pub mod state_vector;
+pub mod tonehole;
+pub mod transfer_matrix;
+pub mod tube;
@@ -0,0 +1,48 @@
+use num_complex::Complex64;
+
+// Acoustic pressure and volume flow at one point of the bore, in Pa and m^3/s up to a
+// common scale factor.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StateVector {
+    pub pressure: Complex64,
+    pub volume_flow: Complex64,
+}
+
+impl StateVector {
+    pub fn new(pressure: Complex64, volume_flow: Complex64) -> Self {
+        return Self {
+            pressure,
+            volume_flow,
+        };
+    }
+
+    // State at a load of known impedance, in kg/(m^4.s).
+    pub fn from_impedance(impedance: Complex64) -> Self {
+        return Self::new(impedance, Complex64::new(1.0, 0.0));
+    }
+
+    // Stopped end, with no flow.
+    pub fn closed_end() -> Self {
+        return Self::new(Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+    }
+
+    // Open end without radiation, with no pressure.
+    pub fn ideal_open_end() -> Self {
+        return Self::new(Complex64::new(0.0, 0.0), Complex64::new(1.0, 0.0));
+    }
+
+    // Impedance p/U in kg/(m^4.s), undefined at a closed end.
+    pub fn impedance(&self) -> Complex64 {
+        return self.pressure / self.volume_flow;
+    }
+
+    // Pressure reflection coefficient relative to a characteristic impedance, such as
+    // PhysicalParameters::wave_impedance of the local bore radius.
+    pub fn reflectance(&self, characteristic_impedance: f64) -> Complex64 {
+        let flow = self.volume_flow * characteristic_impedance;
+        return (self.pressure - flow) / (self.pressure + flow);
+    }
+}
+
+#[cfg(test)]
+mod state_vector_tests;
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod state_vector_tests {
+    use num_complex::Complex64;
+
+    use super::super::*;
+
+    #[test]
+    fn it_reflects_at_the_ends() {
+        let one = Complex64::new(1.0, 0.0);
+        assert_eq!(
+            one,
+            StateVector::closed_end().reflectance(3681860.1456449446)
+        );
+        assert_eq!(
+            -one,
+            StateVector::ideal_open_end().reflectance(3681860.1456449446)
+        );
+        let matched = StateVector::from_impedance(Complex64::new(3681860.1456449446, 0.0));
+        assert_eq!(
+            Complex64::new(0.0, 0.0),
+            matched.reflectance(3681860.1456449446)
+        );
+    }
+
+    #[test]
+    fn it_gives_the_impedance() {
+        let state = StateVector::new(Complex64::new(2.0, 4.0), Complex64::new(0.0, 2.0));
+        assert_eq!(Complex64::new(2.0, -1.0), state.impedance());
+    }
+}
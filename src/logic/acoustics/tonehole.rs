@@ -0,0 +1,405 @@
+use std::{error::Error, f64::consts::PI, fmt};
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    logic::{
+        instrument::{Instrument, LengthUnit},
+        physics::losses::ViscothermalLossModel,
+        structs::hole::Hole,
+    },
+    structs::parameters::PhysicalParameters,
+};
+
+use super::{
+    state_vector::StateVector,
+    transfer_matrix::TransferMatrix,
+    tube::{cylinder_matrix, section_matrix},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HoleState {
+    Open,
+    Closed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ToneholeError {
+    InvalidBoreRadius(f64), // Non-finite or non-positive, or narrower than the hole
+    WrongStateCount(usize), // Number of hole states, differing from the number of holes
+    NotInMetres(LengthUnit), // Unit of an instrument that should be in metres
+}
+
+impl fmt::Display for ToneholeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ToneholeError::InvalidBoreRadius(value) => write!(
+                f,
+                "bore radius {} must be finite, positive and at least the hole radius",
+                value
+            ),
+            ToneholeError::WrongStateCount(count) => {
+                write!(f, "{} hole states given, one per hole is needed", count)
+            }
+            ToneholeError::NotInMetres(unit) => {
+                write!(f, "instrument is in {}, it must be converted to m", unit)
+            }
+        };
+    }
+}
+
+impl Error for ToneholeError {}
+
+// Series and shunt impedances of a tone hole, in kg/(m^4.s), forming the symmetric
+// T network
+//
+//     --[ Za/2 ]--+--[ Za/2 ]--
+//                 |
+//                [Zs]
+//                 |
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ToneholeImpedances {
+    pub series: Complex64, // Za, negative mass from the flow widening into the hole
+    pub shunt: Complex64,  // Zs, the chimney seen from the bore
+}
+
+impl ToneholeImpedances {
+    pub fn transfer_matrix(&self) -> TransferMatrix {
+        let ratio = self.series / self.shunt;
+        let diagonal = 1.0 + 0.5 * ratio;
+        return TransferMatrix::new(
+            diagonal,
+            self.series * (1.0 + 0.25 * ratio),
+            self.shunt.inv(),
+            diagonal,
+        );
+    }
+}
+
+// Impedances of a tone hole of a Hole in m, drilled in a bore of a given radius in m,
+// at least the radius of the hole. Losses along the chimney follow the viscothermal
+// loss model.
+pub trait ToneholeImpedanceModel {
+    fn name(&self) -> &'static str;
+    fn impedances(
+        &self,
+        parameters: &PhysicalParameters,
+        loss_model: &dyn ViscothermalLossModel,
+        frequency: f64,
+        hole: &Hole,
+        bore_radius: f64,
+        state: HoleState,
+    ) -> ToneholeImpedances;
+}
+
+// Radiation impedance of an unflanged pipe of a radius in m, at low frequencies
+// (Levine & Schwinger, 1948).
+fn unflanged_radiation_impedance(
+    parameters: &PhysicalParameters,
+    frequency: f64,
+    radius: f64,
+) -> Complex64 {
+    let k_radius = parameters.wave_number(frequency) * radius;
+    return parameters.wave_impedance(radius)
+        * Complex64::new(0.25 * k_radius * k_radius, 0.6133 * k_radius);
+}
+
+// Shunt impedance shared by the Lefebvre & Scavone and Dubos models: a lossy chimney of the
+// effective height, open to radiation or closed, below an inner length correction ti.
+fn chimney_shunt_impedance(
+    parameters: &PhysicalParameters,
+    loss_model: &dyn ViscothermalLossModel,
+    frequency: f64,
+    hole: &Hole,
+    bore_radius: f64,
+    state: HoleState,
+    inner_length: f64,
+) -> Complex64 {
+    let radius = hole.radius();
+    let chimney = cylinder_matrix(
+        parameters,
+        loss_model,
+        frequency,
+        hole.effective_chimney_length(bore_radius),
+        radius,
+    );
+    let chimney_impedance = match state {
+        HoleState::Open => {
+            chimney.input_impedance(unflanged_radiation_impedance(parameters, frequency, radius))
+        }
+        HoleState::Closed => chimney.input_impedance_of(StateVector::closed_end()),
+    };
+    let inner_mass = Complex64::new(0.0, parameters.wave_number(frequency) * inner_length);
+    return parameters.wave_impedance(radius) * inner_mass + chimney_impedance;
+}
+
+// Series impedance of a negative length correction ta.
+fn series_impedance(
+    parameters: &PhysicalParameters,
+    frequency: f64,
+    hole: &Hole,
+    length: f64,
+) -> Complex64 {
+    return parameters.wave_impedance(hole.radius())
+        * Complex64::new(0.0, parameters.wave_number(frequency) * length);
+}
+
+// Keefe, 1990, "Woodwind air column models". The open hole combines the chimney, the
+// inner and radiation corrections in one effective length, with edge losses from the
+// inner curvature radius rc. The edge loss k.dv/4 ln(2a/rc) diverges as rc goes to 0,
+// where the rounded-edge formula no longer applies, so it is left out for sharp holes
+// (rc = 0, the default of Hole::new). Their damping is underestimated: give a small
+// rc instead to model a sharp edge with some loss.
+pub struct Keefe1990;
+
+impl ToneholeImpedanceModel for Keefe1990 {
+    fn name(&self) -> &'static str {
+        return "Keefe 1990";
+    }
+
+    fn impedances(
+        &self,
+        parameters: &PhysicalParameters,
+        loss_model: &dyn ViscothermalLossModel,
+        frequency: f64,
+        hole: &Hole,
+        bore_radius: f64,
+        state: HoleState,
+    ) -> ToneholeImpedances {
+        let radius = hole.radius();
+        let delta = radius / bore_radius;
+        let height = hole.effective_chimney_length(bore_radius);
+        let wave_number = parameters.wave_number(frequency);
+        let tan = (wave_number * height).tan();
+        let impedance = parameters.wave_impedance(radius);
+        let ratio = 1.84 * hole.height() / radius;
+        return match state {
+            HoleState::Open => {
+                let length = (tan / wave_number + radius * (1.40 - 0.58 * delta * delta))
+                    / (1.0 - 0.61 * wave_number * radius * tan);
+                let chimney_attenuation = loss_model
+                    .propagation_constant(parameters, frequency, radius)
+                    .re;
+                let mut resistance =
+                    0.25 * (wave_number * radius).powi(2) + chimney_attenuation * height;
+                // Not defined for sharp edges, see Keefe1990.
+                if hole.inner_curvature_radius() > 0.0 {
+                    let viscous_layer = (2.0 * parameters.dynamic_viscosity
+                        / (parameters.air_density * 2.0 * PI * frequency))
+                        .sqrt();
+                    resistance += 0.25
+                        * wave_number
+                        * viscous_layer
+                        * (2.0 * radius / hole.inner_curvature_radius()).ln();
+                }
+                let series_length = -0.47 * radius * delta.powi(4)
+                    / (ratio.tanh() + 0.62 * delta * delta + 0.64 * delta);
+                ToneholeImpedances {
+                    series: series_impedance(parameters, frequency, hole, series_length),
+                    shunt: impedance * Complex64::new(resistance, wave_number * length),
+                }
+            }
+            HoleState::Closed => {
+                let series_length = -0.47 * radius * delta.powi(4)
+                    / (1.0 / ratio.tanh() + 0.62 * delta * delta + 0.64 * delta);
+                ToneholeImpedances {
+                    series: series_impedance(parameters, frequency, hole, series_length),
+                    shunt: Complex64::new(0.0, -impedance / tan),
+                }
+            }
+        };
+    }
+}
+
+// Lefebvre & Scavone, 2012, "Characterization of woodwind instrument toneholes with
+// the finite element method", length corrections fitted to FEM results.
+pub struct LefebvreScavone2012;
+
+impl ToneholeImpedanceModel for LefebvreScavone2012 {
+    fn name(&self) -> &'static str {
+        return "Lefebvre & Scavone 2012";
+    }
+
+    fn impedances(
+        &self,
+        parameters: &PhysicalParameters,
+        loss_model: &dyn ViscothermalLossModel,
+        frequency: f64,
+        hole: &Hole,
+        bore_radius: f64,
+        state: HoleState,
+    ) -> ToneholeImpedances {
+        let radius = hole.radius();
+        let delta = radius / bore_radius;
+        let inner_length = radius
+            * (0.822
+                + delta
+                    * (-0.095
+                        + delta * (-1.566 + delta * (2.138 + delta * (-1.640 + delta * 0.502)))));
+        let ratio = hole.height() / radius;
+        let series_length = match state {
+            HoleState::Open => (-0.35 + 0.06 * (2.7 * ratio).tanh()) * radius * delta * delta,
+            HoleState::Closed => (-0.12 - 0.17 * (2.4 * ratio).tanh()) * radius * delta * delta,
+        };
+        return ToneholeImpedances {
+            series: series_impedance(parameters, frequency, hole, series_length),
+            shunt: chimney_shunt_impedance(
+                parameters,
+                loss_model,
+                frequency,
+                hole,
+                bore_radius,
+                state,
+                inner_length,
+            ),
+        };
+    }
+}
+
+// Dubos, Kergomard, Khettabi, Dalmont, Keefe & Nederveen, 1999, "Theory of sound
+// propagation in a duct with a branched tube using modal decomposition".
+pub struct Dubos1999;
+
+impl ToneholeImpedanceModel for Dubos1999 {
+    fn name(&self) -> &'static str {
+        return "Dubos 1999";
+    }
+
+    fn impedances(
+        &self,
+        parameters: &PhysicalParameters,
+        loss_model: &dyn ViscothermalLossModel,
+        frequency: f64,
+        hole: &Hole,
+        bore_radius: f64,
+        state: HoleState,
+    ) -> ToneholeImpedances {
+        let radius = hole.radius();
+        let delta = radius / bore_radius;
+        let inner_length =
+            radius * (0.82 + delta * (-0.193 + delta * (-1.09 + delta * (1.27 - delta * 0.71))));
+        let ratio = 1.84 * hole.height() / radius;
+        let height_term = match state {
+            HoleState::Open => ratio.tanh(),
+            HoleState::Closed => 1.0 / ratio.tanh(),
+        };
+        let series_length = -radius * delta * delta
+            / (1.78 * height_term + 0.940 + 0.540 * delta + 0.285 * delta * delta);
+        return ToneholeImpedances {
+            series: series_impedance(parameters, frequency, hole, series_length),
+            shunt: chimney_shunt_impedance(
+                parameters,
+                loss_model,
+                frequency,
+                hole,
+                bore_radius,
+                state,
+                inner_length,
+            ),
+        };
+    }
+}
+
+// Selection of a built-in tone hole model, for comparing models on a design.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ToneholeModel {
+    Keefe1990,
+    #[default]
+    LefebvreScavone2012,
+    Dubos1999,
+}
+
+impl ToneholeModel {
+    pub const ALL: [ToneholeModel; 3] = [
+        ToneholeModel::Keefe1990,
+        ToneholeModel::LefebvreScavone2012,
+        ToneholeModel::Dubos1999,
+    ];
+
+    pub fn model(self) -> &'static dyn ToneholeImpedanceModel {
+        return match self {
+            ToneholeModel::Keefe1990 => &Keefe1990,
+            ToneholeModel::LefebvreScavone2012 => &LefebvreScavone2012,
+            ToneholeModel::Dubos1999 => &Dubos1999,
+        };
+    }
+}
+
+pub fn tonehole_matrix(
+    parameters: &PhysicalParameters,
+    loss_model: &dyn ViscothermalLossModel,
+    model: &dyn ToneholeImpedanceModel,
+    frequency: f64,
+    hole: &Hole,
+    bore_radius: f64,
+    state: HoleState,
+) -> Result<TransferMatrix, ToneholeError> {
+    if !bore_radius.is_finite() || bore_radius < hole.radius() {
+        return Err(ToneholeError::InvalidBoreRadius(bore_radius));
+    }
+    return Ok(model
+        .impedances(parameters, loss_model, frequency, hole, bore_radius, state)
+        .transfer_matrix());
+}
+
+// Matrix of the air column from the mouthpiece to the foot, with every hole in the
+// given state inserted between the bore sections. The bore above the mouthpiece is
+// left out, and the load at the foot is up to the caller.
+// The instrument must be in metres: convert it once with Instrument::to_metres before
+// sweeping frequencies, rather than on every call.
+pub fn instrument_matrix(
+    parameters: &PhysicalParameters,
+    loss_model: &dyn ViscothermalLossModel,
+    model: &dyn ToneholeImpedanceModel,
+    frequency: f64,
+    instrument: &Instrument,
+    states: &[HoleState],
+) -> Result<TransferMatrix, ToneholeError> {
+    if instrument.unit() != LengthUnit::Metre {
+        return Err(ToneholeError::NotInMetres(instrument.unit()));
+    }
+    if states.len() != instrument.holes().len() {
+        return Err(ToneholeError::WrongStateCount(states.len()));
+    }
+    let mouthpiece = instrument.mouthpiece().position();
+    let mut holes = instrument
+        .holes()
+        .iter()
+        .zip(states)
+        .filter(|(hole, _)| hole.position() >= mouthpiece)
+        .peekable();
+    let mut chain = TransferMatrix::identity();
+    let mut insert_holes_up_to = |chain: &mut TransferMatrix, position: f64| {
+        while let Some((hole, state)) = holes.next_if(|(hole, _)| hole.position() <= position) {
+            // Instrument validation keeps every hole inside the bore.
+            let bore_radius = instrument.bore().radius_at(hole.position()).unwrap_or(0.0);
+            *chain = *chain
+                * tonehole_matrix(
+                    parameters,
+                    loss_model,
+                    model,
+                    frequency,
+                    hole,
+                    bore_radius,
+                    *state,
+                )?;
+        }
+        return Ok(());
+    };
+    for section in instrument
+        .sections()
+        .iter()
+        .filter(|section| section.start >= mouthpiece)
+    {
+        insert_holes_up_to(&mut chain, section.start)?;
+        chain = chain * section_matrix(parameters, loss_model, frequency, section);
+    }
+    insert_holes_up_to(&mut chain, f64::INFINITY)?;
+    return Ok(chain);
+}
+
+#[cfg(test)]
+mod tonehole_tests;
@@ -0,0 +1,257 @@
+#[cfg(test)]
+mod tonehole_tests {
+    use crate::{
+        logic::{
+            acoustics::state_vector::StateVector,
+            instrument::{bore::BoreProfile, LengthUnit, Mouthpiece, Termination},
+            physics::losses::{Keefe1984, Lossless},
+        },
+        structs::parameters::ParametersBuilder,
+    };
+
+    use super::super::*;
+
+    fn parameters() -> PhysicalParameters {
+        return ParametersBuilder::new().build();
+    }
+
+    fn hole() -> Hole {
+        return Hole::builder("T1")
+            .with_position(0.3)
+            .with_diameter(0.008)
+            .with_height(0.004)
+            .with_inner_curvature_radius(0.0005)
+            .build()
+            .unwrap();
+    }
+
+    // First minimum of the input impedance with an ideal open foot.
+    fn first_resonance(instrument: &Instrument, states: &[HoleState], model: ToneholeModel) -> f64 {
+        let parameters = parameters();
+        let instrument = instrument.to_metres();
+        let impedance = |frequency: f64| {
+            let matrix = instrument_matrix(
+                &parameters,
+                &Keefe1984,
+                model.model(),
+                frequency,
+                &instrument,
+                states,
+            )
+            .unwrap();
+            return matrix
+                .input_impedance_of(StateVector::ideal_open_end())
+                .norm();
+        };
+        return (0..4000)
+            .map(|step| 200.0 + 0.25 * step as f64)
+            .min_by(|a, b| impedance(*a).total_cmp(&impedance(*b)))
+            .unwrap();
+    }
+
+    fn flute(holes: Vec<Hole>) -> Instrument {
+        return Instrument::new(
+            "test",
+            LengthUnit::Millimetre,
+            BoreProfile::cylinder(600.0, 19.0).unwrap(),
+            Mouthpiece::Closed { position: 0.0 },
+            Termination::Open,
+            holes,
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn it_builds_reciprocal_matrices() {
+        let parameters = parameters();
+        for model in ToneholeModel::ALL {
+            for state in [HoleState::Open, HoleState::Closed] {
+                let matrix = tonehole_matrix(
+                    &parameters,
+                    &Keefe1984,
+                    model.model(),
+                    440.0,
+                    &hole(),
+                    0.0095,
+                    state,
+                )
+                .unwrap();
+                assert!((matrix.determinant() - 1.0).norm() < 1e-9);
+                assert_eq!(matrix.pp, matrix.uu);
+            }
+        }
+    }
+
+    #[test]
+    fn it_makes_open_holes_low_impedance_branches() {
+        let parameters = parameters();
+        for model in ToneholeModel::ALL {
+            let impedances = |state| {
+                return model.model().impedances(
+                    &parameters,
+                    &Keefe1984,
+                    440.0,
+                    &hole(),
+                    0.0095,
+                    state,
+                );
+            };
+            let (open, closed) = (impedances(HoleState::Open), impedances(HoleState::Closed));
+            // Open: an inertance with some resistance. Closed: a small compliance.
+            assert!(open.shunt.im > 0.0 && open.shunt.re > 0.0);
+            assert!(closed.shunt.im < 0.0);
+            assert!(open.shunt.norm() < 0.1 * closed.shunt.norm());
+            assert!(open.series.im < 0.0 && closed.series.im <= 0.0);
+        }
+    }
+
+    #[test]
+    fn it_agrees_between_models_on_the_open_hole_length() {
+        let parameters = parameters();
+        let wave_number = parameters.wave_number(440.0);
+        let impedance = parameters.wave_impedance(hole().radius());
+        let lengths: Vec<f64> = ToneholeModel::ALL
+            .iter()
+            .map(|model| {
+                let shunt = model
+                    .model()
+                    .impedances(
+                        &parameters,
+                        &Lossless,
+                        440.0,
+                        &hole(),
+                        0.0095,
+                        HoleState::Open,
+                    )
+                    .shunt;
+                return shunt.im / (impedance * wave_number);
+            })
+            .collect();
+        let height = hole().effective_chimney_length(0.0095);
+        for length in &lengths {
+            assert!(*length > height + 0.004 && *length < height + 0.01);
+            assert!((length - lengths[1]).abs() < 0.15 * lengths[1]);
+        }
+    }
+
+    #[test]
+    fn it_inserts_holes_into_the_bore_chain() {
+        let parameters = parameters();
+        let hole = Hole::new("T1", 400.0, 10.0, 4.0).unwrap();
+        let instrument = flute(vec![hole.clone()]);
+        let bore = flute(vec![]);
+        let model = ToneholeModel::default();
+
+        // A closed hole barely changes the tube, an open one shortens it.
+        let plain = first_resonance(&bore, &[], model);
+        let closed = first_resonance(&instrument, &[HoleState::Closed], model);
+        let open = first_resonance(&instrument, &[HoleState::Open], model);
+        assert!((closed - plain).abs() < 0.01 * plain);
+        assert!(open > 1.2 * plain);
+        assert!(open < parameters.sound_speed / (2.0 * 0.4));
+
+        let metres = instrument.to_metres();
+        let matrix = instrument_matrix(
+            &parameters,
+            &Keefe1984,
+            model.model(),
+            440.0,
+            &metres,
+            &[HoleState::Open],
+        )
+        .unwrap();
+        let sections = metres.sections();
+        let expected = section_matrix(&parameters, &Keefe1984, 440.0, &sections[0])
+            * tonehole_matrix(
+                &parameters,
+                &Keefe1984,
+                model.model(),
+                440.0,
+                &metres.holes()[0],
+                0.0095,
+                HoleState::Open,
+            )
+            .unwrap()
+            * section_matrix(&parameters, &Keefe1984, 440.0, &sections[1]);
+        assert!((expected.pu - matrix.pu).norm() < 1e-9 * expected.pu.norm());
+    }
+
+    #[test]
+    fn it_compares_models_on_a_design() {
+        let instrument = flute(vec![Hole::new("T1", 400.0, 10.0, 4.0).unwrap()]);
+        let resonances: Vec<f64> = ToneholeModel::ALL
+            .iter()
+            .map(|model| first_resonance(&instrument, &[HoleState::Open], *model))
+            .collect();
+        let spread = resonances.iter().cloned().fold(f64::MIN, f64::max)
+            - resonances.iter().cloned().fold(f64::MAX, f64::min);
+        assert!(spread > 0.0 && spread < 0.03 * resonances[1]);
+    }
+
+    #[test]
+    fn it_requires_a_state_per_hole_in_metres() {
+        let instrument = flute(vec![hole().scaled(1000.0)]);
+        let actual = instrument_matrix(
+            &parameters(),
+            &Keefe1984,
+            &Dubos1999,
+            440.0,
+            &instrument.to_metres(),
+            &[],
+        );
+        assert_eq!(Err(ToneholeError::WrongStateCount(0)), actual);
+        let actual = instrument_matrix(
+            &parameters(),
+            &Keefe1984,
+            &Dubos1999,
+            440.0,
+            &instrument,
+            &[HoleState::Open],
+        );
+        assert_eq!(
+            Err(ToneholeError::NotInMetres(LengthUnit::Millimetre)),
+            actual
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_bore_radii() {
+        let parameters = parameters();
+        for bore_radius in [0.0, -0.0095, 0.003, f64::INFINITY] {
+            let actual = tonehole_matrix(
+                &parameters,
+                &Keefe1984,
+                &Keefe1990,
+                440.0,
+                &hole(),
+                bore_radius,
+                HoleState::Open,
+            );
+            assert_eq!(Err(ToneholeError::InvalidBoreRadius(bore_radius)), actual);
+        }
+        assert!(tonehole_matrix(
+            &parameters,
+            &Keefe1984,
+            &Keefe1990,
+            440.0,
+            &hole(),
+            f64::NAN,
+            HoleState::Open,
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn it_serializes_the_model_choice() {
+        let json = serde_json::to_string(&ToneholeModel::Dubos1999).unwrap();
+        assert_eq!("\"dubos1999\"", json);
+        assert_eq!(
+            "Dubos 1999",
+            serde_json::from_str::<ToneholeModel>(&json)
+                .unwrap()
+                .model()
+                .name()
+        );
+        assert_eq!("\"open\"", serde_json::to_string(&HoleState::Open).unwrap());
+    }
+}
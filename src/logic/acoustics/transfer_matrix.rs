@@ -0,0 +1,110 @@
+use std::ops::Mul;
+
+use num_complex::Complex64;
+
+use super::state_vector::StateVector;
+
+// Complex 2x2 matrix relating the state at the upstream end of an element to the state
+// at its downstream end:
+//
+//     | p1 |   | pp  pu | | p2 |
+//     | U1 | = | up  uu | | U2 |
+//
+// Elements are chained from the mouthpiece down by multiplying in that order.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TransferMatrix {
+    pub pp: Complex64,
+    pub pu: Complex64, // kg/(m^4.s)
+    pub up: Complex64, // m^4.s/kg
+    pub uu: Complex64,
+}
+
+impl TransferMatrix {
+    pub fn new(pp: Complex64, pu: Complex64, up: Complex64, uu: Complex64) -> Self {
+        return Self { pp, pu, up, uu };
+    }
+
+    pub fn identity() -> Self {
+        let (one, zero) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+        return Self::new(one, zero, zero, one);
+    }
+
+    // Impedance in series with the flow, such as the mass of a constriction.
+    pub fn series_impedance(impedance: Complex64) -> Self {
+        let (one, zero) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+        return Self::new(one, impedance, zero, one);
+    }
+
+    // Impedance branching off the bore, such as a side hole.
+    pub fn shunt_impedance(impedance: Complex64) -> Self {
+        let one = Complex64::new(1.0, 0.0);
+        return Self::new(one, Complex64::new(0.0, 0.0), impedance.inv(), one);
+    }
+
+    // Product of elements given from upstream to downstream.
+    pub fn chain<I: IntoIterator<Item = TransferMatrix>>(elements: I) -> Self {
+        return elements
+            .into_iter()
+            .fold(Self::identity(), |chain, element| chain * element);
+    }
+
+    // 1 for any reciprocal element.
+    pub fn determinant(&self) -> Complex64 {
+        return self.pp * self.uu - self.pu * self.up;
+    }
+
+    // Impedance at the upstream end with a load impedance at the downstream end.
+    pub fn input_impedance(&self, load_impedance: Complex64) -> Complex64 {
+        return self.input_impedance_of(StateVector::from_impedance(load_impedance));
+    }
+
+    // Impedance at the upstream end with a load state, such as a closed end.
+    pub fn input_impedance_of(&self, load: StateVector) -> Complex64 {
+        return (self * load).impedance();
+    }
+
+    // Reflectance at the upstream end, relative to the characteristic impedance there.
+    pub fn reflectance(
+        &self,
+        load_impedance: Complex64,
+        characteristic_impedance: f64,
+    ) -> Complex64 {
+        return (self * StateVector::from_impedance(load_impedance))
+            .reflectance(characteristic_impedance);
+    }
+}
+
+impl Mul for TransferMatrix {
+    type Output = TransferMatrix;
+
+    fn mul(self, other: TransferMatrix) -> TransferMatrix {
+        return TransferMatrix::new(
+            self.pp * other.pp + self.pu * other.up,
+            self.pp * other.pu + self.pu * other.uu,
+            self.up * other.pp + self.uu * other.up,
+            self.up * other.pu + self.uu * other.uu,
+        );
+    }
+}
+
+impl Mul<StateVector> for &TransferMatrix {
+    type Output = StateVector;
+
+    fn mul(self, state: StateVector) -> StateVector {
+        return StateVector::new(
+            self.pp * state.pressure + self.pu * state.volume_flow,
+            self.up * state.pressure + self.uu * state.volume_flow,
+        );
+    }
+}
+
+impl Mul<StateVector> for TransferMatrix {
+    type Output = StateVector;
+
+    fn mul(self, state: StateVector) -> StateVector {
+        return &self * state;
+    }
+}
+
+#[cfg(test)]
+mod transfer_matrix_tests;
@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod transfer_matrix_tests {
+    use num_complex::Complex64;
+
+    use super::super::*;
+
+    fn assert_close(expected: Complex64, actual: Complex64) {
+        assert!(
+            (expected - actual).norm() <= 1e-12 * expected.norm().max(1.0),
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn it_chains_elements() {
+        let mass = TransferMatrix::series_impedance(Complex64::new(0.0, 2.0));
+        let hole = TransferMatrix::shunt_impedance(Complex64::new(1.0, 1.0));
+        assert_eq!(mass, TransferMatrix::chain([mass]));
+        assert_eq!(mass * hole, TransferMatrix::chain([mass, hole]));
+        assert_eq!(mass, mass * TransferMatrix::identity());
+        assert_eq!(TransferMatrix::identity(), TransferMatrix::chain([]));
+        assert_close(Complex64::new(1.0, 0.0), (mass * hole).determinant());
+    }
+
+    #[test]
+    fn it_transforms_load_impedances() {
+        let load = Complex64::new(3.0, -1.0);
+        let mass = Complex64::new(0.0, 2.0);
+        let series = TransferMatrix::series_impedance(mass);
+        assert_close(load + mass, series.input_impedance(load));
+
+        let shunt = TransferMatrix::shunt_impedance(mass);
+        assert_close(load * mass / (load + mass), shunt.input_impedance(load));
+        assert_close(mass, shunt.input_impedance_of(StateVector::closed_end()));
+    }
+
+    #[test]
+    fn it_applies_to_state_vectors() {
+        let matrix = TransferMatrix::new(
+            Complex64::new(1.0, 0.0),
+            Complex64::new(2.0, 0.0),
+            Complex64::new(3.0, 0.0),
+            Complex64::new(4.0, 0.0),
+        );
+        let state = matrix * StateVector::new(Complex64::new(1.0, 0.0), Complex64::new(0.0, 1.0));
+        assert_eq!(Complex64::new(1.0, 2.0), state.pressure);
+        assert_eq!(Complex64::new(3.0, 4.0), state.volume_flow);
+        let reflectance = matrix.reflectance(Complex64::new(1.0, 0.0), 1.0);
+        assert_close(Complex64::new(-0.4, 0.0), reflectance);
+    }
+}
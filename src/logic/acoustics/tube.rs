@@ -0,0 +1,127 @@
+use num_complex::Complex64;
+
+use crate::{
+    logic::{instrument::bore::BoreSection, physics::losses::ViscothermalLossModel},
+    structs::parameters::PhysicalParameters,
+};
+
+use super::transfer_matrix::TransferMatrix;
+
+// Lossy cylinder of a length and radius in m.
+pub fn cylinder_matrix(
+    parameters: &PhysicalParameters,
+    model: &dyn ViscothermalLossModel,
+    frequency: f64,
+    length: f64,
+    radius: f64,
+) -> TransferMatrix {
+    let gamma_length = model.propagation_constant(parameters, frequency, radius) * length;
+    let impedance = model.characteristic_impedance(parameters, frequency, radius);
+    let (cosh, sinh) = (gamma_length.cosh(), gamma_length.sinh());
+    return TransferMatrix::new(cosh, impedance * sinh, sinh / impedance, cosh);
+}
+
+// Below this |G L| the 1/G terms of cone_matrix are taken from their Taylor series,
+// where the closed forms lose precision to cancellation and are 0/0 at 0 Hz.
+const SERIES_THRESHOLD: f64 = 0.01;
+
+// sinh(z)/z, 1 at z = 0.
+fn sinh_over(z: Complex64) -> Complex64 {
+    if z.norm() < SERIES_THRESHOLD {
+        let z2 = z * z;
+        return 1.0 + z2 / 6.0 + z2 * z2 / 120.0;
+    }
+    return z.sinh() / z;
+}
+
+// (cosh(z) - sinh(z)/z)/z^2, 1/3 at z = 0.
+fn compliance_factor(z: Complex64) -> Complex64 {
+    if z.norm() < SERIES_THRESHOLD {
+        let z2 = z * z;
+        return 1.0 / 3.0 + z2 / 30.0 + z2 * z2 / 840.0;
+    }
+    return (z.cosh() - z.sinh() / z) / (z * z);
+}
+
+// Lossy cone of a length in m, from a source radius upstream to a load radius downstream.
+//
+// Spherical waves with distances x1 and x2 = x1 + L from the apex, x1 being negative
+// for cones narrowing downstream:
+//
+//     pp = x2/x1 cosh(GL) - sinh(GL)/(G x1)
+//     pu = Z1 x1/x2 sinh(GL)
+//     up = ((x2/x1 - 1/(G x1)^2) sinh(GL) + L/(G x1^2) cosh(GL)) / Z1
+//     uu = x1/x2 cosh(GL) + sinh(GL)/(G x2)
+//
+// with Z1 the characteristic impedance at the source, and the propagation constant G
+// taken at the radius whose losses equal the mean losses along the cone. The 1/G terms
+// are computed through sinh(GL)/GL and (cosh(GL) - sinh(GL)/GL)/(GL)^2, which stay
+// finite as G goes to 0: at 0 Hz the matrix is the identity, like the cylinder's, and
+// at low frequencies up tends to the compliance j.omega.V/(rho.c^2) of the cone volume.
+pub fn cone_matrix(
+    parameters: &PhysicalParameters,
+    model: &dyn ViscothermalLossModel,
+    frequency: f64,
+    length: f64,
+    source_radius: f64,
+    load_radius: f64,
+) -> TransferMatrix {
+    if source_radius == load_radius {
+        return cylinder_matrix(parameters, model, frequency, length, source_radius);
+    }
+    // Boundary-layer losses go as 1/r, whose mean along the cone is ln(r2/r1)/(r2 - r1).
+    let loss_radius = (load_radius - source_radius) / (load_radius / source_radius).ln();
+    let gamma = model.propagation_constant(parameters, frequency, loss_radius);
+    let impedance = model.characteristic_impedance(parameters, frequency, source_radius);
+
+    let source_distance = source_radius * length / (load_radius - source_radius);
+    let load_distance = source_distance + length;
+    let ratio = load_distance / source_distance;
+    let gamma_length = gamma * length;
+    let (cosh, sinh) = (gamma_length.cosh(), gamma_length.sinh());
+    // sinh(GL)/G, L at 0 Hz.
+    let sinh_length = length * sinh_over(gamma_length);
+    return TransferMatrix::new(
+        ratio * cosh - sinh_length / source_distance,
+        impedance * sinh / ratio,
+        gamma
+            * (ratio * sinh_length
+                + length.powi(3) * compliance_factor(gamma_length) / source_distance.powi(2))
+            / impedance,
+        cosh / ratio + sinh_length / load_distance,
+    );
+}
+
+// Matrix of one bore section, with lengths in m.
+pub fn section_matrix(
+    parameters: &PhysicalParameters,
+    model: &dyn ViscothermalLossModel,
+    frequency: f64,
+    section: &BoreSection,
+) -> TransferMatrix {
+    return cone_matrix(
+        parameters,
+        model,
+        frequency,
+        section.length,
+        section.start_radius(),
+        section.end_radius(),
+    );
+}
+
+// Matrix of consecutive bore sections from the top of the bore down, with lengths in m.
+pub fn bore_matrix(
+    parameters: &PhysicalParameters,
+    model: &dyn ViscothermalLossModel,
+    frequency: f64,
+    sections: &[BoreSection],
+) -> TransferMatrix {
+    return TransferMatrix::chain(
+        sections
+            .iter()
+            .map(|section| section_matrix(parameters, model, frequency, section)),
+    );
+}
+
+#[cfg(test)]
+mod tube_tests;
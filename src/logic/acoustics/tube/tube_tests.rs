@@ -0,0 +1,188 @@
+#[cfg(test)]
+mod tube_tests {
+    use num_complex::Complex64;
+
+    use crate::{
+        logic::{
+            acoustics::state_vector::StateVector,
+            instrument::bore::{BorePoint, BoreProfile},
+            physics::losses::{Keefe1984, Lossless, ZwikkerKosten},
+        },
+        structs::parameters::{ParametersBuilder, PhysicalParameters},
+    };
+
+    use super::super::*;
+
+    fn parameters() -> PhysicalParameters {
+        return ParametersBuilder::new().build();
+    }
+
+    fn assert_close(expected: Complex64, actual: Complex64, tolerance: f64) {
+        assert!(
+            (expected - actual).norm() <= tolerance * expected.norm(),
+            "expected {}, got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn it_matches_the_lossless_cylinder() {
+        let parameters = parameters();
+        let (length, radius, frequency) = (0.3, 0.008, 440.0);
+        let matrix = cylinder_matrix(&parameters, &Lossless, frequency, length, radius);
+        let k_length = parameters.wave_number(frequency) * length;
+        let impedance = parameters.wave_impedance(radius);
+        // Closed far end: Zin = -j Zc cot(kL).
+        let expected = Complex64::new(0.0, -impedance / k_length.tan());
+        assert_close(
+            expected,
+            matrix.input_impedance_of(StateVector::closed_end()),
+            1e-12,
+        );
+        // No losses, so everything is reflected back.
+        let reflectance = (matrix * StateVector::closed_end()).reflectance(impedance);
+        assert!((reflectance.norm() - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_resonates_at_the_half_wavelength() {
+        let parameters = parameters();
+        let length = 0.6;
+        let resonance = parameters.sound_speed / (2.0 * length);
+        let impedance = |frequency: f64| {
+            let matrix = cylinder_matrix(&parameters, &Keefe1984, frequency, length, 0.0095);
+            return matrix.input_impedance(Complex64::new(0.0, 0.0)).norm();
+        };
+        // Dispersion from the losses moves the minimum about 1 % below c/2L.
+        let minimum = (0..400)
+            .map(|step| resonance * (0.97 + 0.0001 * step as f64))
+            .min_by(|a, b| impedance(*a).total_cmp(&impedance(*b)))
+            .unwrap();
+        assert!(minimum < 0.995 * resonance && minimum > 0.985 * resonance);
+        assert!(impedance(minimum) < 0.05 * parameters.wave_impedance(0.0095));
+    }
+
+    #[test]
+    fn it_builds_reciprocal_matrices() {
+        let parameters = parameters();
+        let one = Complex64::new(1.0, 0.0);
+        for frequency in [50.0, 440.0, 3000.0] {
+            let cylinder = cylinder_matrix(&parameters, &ZwikkerKosten, frequency, 0.2, 0.004);
+            assert_close(one, cylinder.determinant(), 1e-9);
+            let widening = cone_matrix(&parameters, &Keefe1984, frequency, 0.2, 0.004, 0.01);
+            assert_close(one, widening.determinant(), 1e-9);
+            let narrowing = cone_matrix(&parameters, &Keefe1984, frequency, 0.2, 0.01, 0.004);
+            assert_close(one, narrowing.determinant(), 1e-9);
+        }
+    }
+
+    #[test]
+    fn it_is_the_identity_at_zero_frequency() {
+        let parameters = parameters();
+        let (one, zero) = (Complex64::new(1.0, 0.0), Complex64::new(0.0, 0.0));
+        let matrices = [
+            cylinder_matrix(&parameters, &ZwikkerKosten, 0.0, 0.2, 0.004),
+            cone_matrix(&parameters, &Keefe1984, 0.0, 0.2, 0.004, 0.01),
+            cone_matrix(&parameters, &ZwikkerKosten, 0.0, 0.2, 0.01, 0.004),
+        ];
+        for matrix in matrices {
+            // x2/x1 - L/x1 is 1 up to rounding for the cones.
+            assert_close(one, matrix.pp, 1e-12);
+            assert_eq!(zero, matrix.pu);
+            assert_eq!(zero, matrix.up);
+            assert_close(one, matrix.uu, 1e-12);
+        }
+    }
+
+    #[test]
+    fn it_tends_to_the_cone_compliance_at_low_frequencies() {
+        // up = j.omega.V/(rho.c^2), with V = pi.L.(r1^2 + r1.r2 + r2^2)/3.
+        let parameters = parameters();
+        let (frequency, length, source_radius, load_radius) = (0.01, 0.2, 0.004, 0.01);
+        let matrix = cone_matrix(
+            &parameters,
+            &Lossless,
+            frequency,
+            length,
+            source_radius,
+            load_radius,
+        );
+        let volume = std::f64::consts::PI
+            * length
+            * (source_radius.powi(2) + source_radius * load_radius + load_radius.powi(2))
+            / 3.0;
+        let omega = 2.0 * std::f64::consts::PI * frequency;
+        let compliance = omega * volume
+            / (parameters.air_density * parameters.sound_speed * parameters.sound_speed);
+        assert_close(Complex64::new(0.0, compliance), matrix.up, 1e-6);
+        assert_close(Complex64::new(1.0, 0.0), matrix.pp, 1e-6);
+    }
+
+    #[test]
+    fn it_splits_cones_without_changing_them() {
+        let parameters = parameters();
+        let whole = cone_matrix(&parameters, &Lossless, 440.0, 0.3, 0.005, 0.011);
+        let halves = cone_matrix(&parameters, &Lossless, 440.0, 0.15, 0.005, 0.008)
+            * cone_matrix(&parameters, &Lossless, 440.0, 0.15, 0.008, 0.011);
+        assert_close(whole.pp, halves.pp, 1e-9);
+        assert_close(whole.pu, halves.pu, 1e-9);
+        assert_close(whole.up, halves.up, 1e-9);
+        assert_close(whole.uu, halves.uu, 1e-9);
+    }
+
+    #[test]
+    fn it_approaches_the_cylinder_for_small_tapers() {
+        let parameters = parameters();
+        let cylinder = cylinder_matrix(&parameters, &Keefe1984, 440.0, 0.3, 0.008);
+        let cone = cone_matrix(
+            &parameters,
+            &Keefe1984,
+            440.0,
+            0.3,
+            0.008,
+            0.008 * (1.0 + 1e-7),
+        );
+        assert_close(cylinder.pp, cone.pp, 1e-5);
+        assert_close(cylinder.pu, cone.pu, 1e-5);
+        assert_close(cylinder.up, cone.up, 1e-5);
+        assert_eq!(
+            cylinder,
+            cone_matrix(&parameters, &Keefe1984, 440.0, 0.3, 0.008, 0.008)
+        );
+    }
+
+    #[test]
+    fn it_chains_bore_sections() {
+        let parameters = parameters();
+        let profile = BoreProfile::new(vec![
+            BorePoint {
+                position: 0.0,
+                diameter: 0.017,
+            },
+            BorePoint {
+                position: 0.15,
+                diameter: 0.019,
+            },
+            BorePoint {
+                position: 0.6,
+                diameter: 0.019,
+            },
+        ])
+        .unwrap();
+        let sections = profile.sections();
+        let matrix = bore_matrix(&parameters, &Keefe1984, 440.0, &sections);
+        let expected = cone_matrix(&parameters, &Keefe1984, 440.0, 0.15, 0.0085, 0.0095)
+            * cylinder_matrix(&parameters, &Keefe1984, 440.0, 0.45, 0.0095);
+        assert_close(expected.pp, matrix.pp, 1e-12);
+        assert_close(expected.up, matrix.up, 1e-12);
+        let split = bore_matrix(
+            &parameters,
+            &Lossless,
+            440.0,
+            &profile.sections_split_at(&[0.3]),
+        );
+        let whole = bore_matrix(&parameters, &Lossless, 440.0, &sections);
+        assert_close(whole.pu, split.pu, 1e-9);
+    }
+}
@@ -0,0 +1,209 @@
+use std::{error::Error, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::logic::structs::hole::Hole;
+
+// Inner diameter of the bore at one axial position.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct BorePoint {
+    pub position: f64, // Distance from the top of the bore
+    pub diameter: f64,
+}
+
+// Part of the bore between two positions, cylindrical when both diameters are equal
+// and conical otherwise.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoreSection {
+    pub start: f64, // Distance from the top of the bore
+    pub length: f64,
+    pub start_diameter: f64,
+    pub end_diameter: f64,
+}
+
+impl BoreSection {
+    pub fn end(&self) -> f64 {
+        return self.start + self.length;
+    }
+
+    pub fn start_radius(&self) -> f64 {
+        return self.start_diameter / 2.0;
+    }
+
+    pub fn end_radius(&self) -> f64 {
+        return self.end_diameter / 2.0;
+    }
+
+    pub fn is_cylindrical(&self) -> bool {
+        return self.start_diameter == self.end_diameter;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoreError {
+    TooFewPoints,
+    InvalidPoint(usize), // Index of a point with a non-finite value or non-positive diameter
+    UnorderedPosition(usize), // Index of the first point not after its predecessor
+}
+
+impl fmt::Display for BoreError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            BoreError::TooFewPoints => write!(f, "a bore needs at least two points"),
+            BoreError::InvalidPoint(index) => write!(f, "bore point {} is invalid", index),
+            BoreError::UnorderedPosition(index) => write!(
+                f,
+                "bore point {} is not further along the bore than the previous one",
+                index
+            ),
+        };
+    }
+}
+
+impl Error for BoreError {}
+
+// Bore from its top to its foot, given by points in increasing position.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "Vec<BorePoint>", into = "Vec<BorePoint>")]
+pub struct BoreProfile {
+    points: Vec<BorePoint>,
+}
+
+impl BoreProfile {
+    pub fn new(points: Vec<BorePoint>) -> Result<Self, BoreError> {
+        if points.len() < 2 {
+            return Err(BoreError::TooFewPoints);
+        }
+        for (index, point) in points.iter().enumerate() {
+            if !point.position.is_finite() || point.diameter <= 0.0 || !point.diameter.is_finite() {
+                return Err(BoreError::InvalidPoint(index));
+            }
+            if index > 0 && point.position <= points[index - 1].position {
+                return Err(BoreError::UnorderedPosition(index));
+            }
+        }
+        return Ok(Self { points });
+    }
+
+    // Straight tube of a given length and diameter, starting at position 0.
+    pub fn cylinder(length: f64, diameter: f64) -> Result<Self, BoreError> {
+        return Self::new(vec![
+            BorePoint {
+                position: 0.0,
+                diameter,
+            },
+            BorePoint {
+                position: length,
+                diameter,
+            },
+        ]);
+    }
+
+    pub fn points(&self) -> &[BorePoint] {
+        return &self.points;
+    }
+
+    pub fn start(&self) -> f64 {
+        return self.points[0].position;
+    }
+
+    pub fn end(&self) -> f64 {
+        return self.points[self.points.len() - 1].position;
+    }
+
+    pub fn length(&self) -> f64 {
+        return self.end() - self.start();
+    }
+
+    pub fn contains(&self, position: f64) -> bool {
+        return (self.start()..=self.end()).contains(&position);
+    }
+
+    // Diameter at a position, interpolated linearly between points, or None outside the bore.
+    pub fn diameter_at(&self, position: f64) -> Option<f64> {
+        if !self.contains(position) {
+            return None;
+        }
+        let index = self
+            .points
+            .partition_point(|point| point.position < position)
+            .max(1);
+        let (left, right) = (self.points[index - 1], self.points[index]);
+        let fraction = (position - left.position) / (right.position - left.position);
+        return Some(left.diameter + fraction * (right.diameter - left.diameter));
+    }
+
+    pub fn radius_at(&self, position: f64) -> Option<f64> {
+        return self.diameter_at(position).map(|diameter| diameter / 2.0);
+    }
+
+    // Bore diameter at the axis of a hole, or None if the hole is outside the bore.
+    pub fn diameter_under(&self, hole: &Hole) -> Option<f64> {
+        return self.diameter_at(hole.position());
+    }
+
+    // One section between each pair of consecutive points.
+    pub fn sections(&self) -> Vec<BoreSection> {
+        return self
+            .points
+            .windows(2)
+            .map(|pair| BoreSection {
+                start: pair[0].position,
+                length: pair[1].position - pair[0].position,
+                start_diameter: pair[0].diameter,
+                end_diameter: pair[1].diameter,
+            })
+            .collect();
+    }
+
+    // Sections further split at every given position inside the bore, such as the
+    // positions of the holes and the mouthpiece.
+    pub fn sections_split_at(&self, positions: &[f64]) -> Vec<BoreSection> {
+        let mut splits: Vec<f64> = positions
+            .iter()
+            .copied()
+            .filter(|position| *position > self.start() && *position < self.end())
+            .collect();
+        splits.sort_by(f64::total_cmp);
+        let mut points = self.points.clone();
+        for position in splits {
+            if let Some(diameter) = self.diameter_at(position) {
+                let index = points.partition_point(|point| point.position < position);
+                if points[index].position != position {
+                    points.insert(index, BorePoint { position, diameter });
+                }
+            }
+        }
+        return Self { points }.sections();
+    }
+
+    // Same profile with every dimension multiplied by a factor, for unit conversions.
+    pub(crate) fn scaled(&self, factor: f64) -> Self {
+        let points = self
+            .points
+            .iter()
+            .map(|point| BorePoint {
+                position: point.position * factor,
+                diameter: point.diameter * factor,
+            })
+            .collect();
+        return Self { points };
+    }
+}
+
+impl TryFrom<Vec<BorePoint>> for BoreProfile {
+    type Error = BoreError;
+
+    fn try_from(points: Vec<BorePoint>) -> Result<Self, Self::Error> {
+        return Self::new(points);
+    }
+}
+
+impl From<BoreProfile> for Vec<BorePoint> {
+    fn from(profile: BoreProfile) -> Self {
+        return profile.points;
+    }
+}
+
+#[cfg(test)]
+mod bore_tests;
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod bore_tests {
+    use super::super::*;
+
+    // Tapered head joint, cylindrical body and flared foot, in mm.
+    fn profile() -> BoreProfile {
+        let points = [(0.0, 17.0), (150.0, 19.0), (500.0, 19.0), (600.0, 23.0)];
+        return BoreProfile::new(
+            points
+                .iter()
+                .map(|(position, diameter)| BorePoint {
+                    position: *position,
+                    diameter: *diameter,
+                })
+                .collect(),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn it_interpolates_the_diameter() {
+        let profile = profile();
+        assert_eq!(Some(17.0), profile.diameter_at(0.0));
+        assert_eq!(Some(18.0), profile.diameter_at(75.0));
+        assert_eq!(Some(19.0), profile.diameter_at(150.0));
+        assert_eq!(Some(19.0), profile.diameter_at(300.0));
+        assert_eq!(Some(21.0), profile.diameter_at(550.0));
+        assert_eq!(Some(23.0), profile.diameter_at(600.0));
+        assert_eq!(Some(9.0), profile.radius_at(75.0));
+        assert_eq!(None, profile.diameter_at(-1.0));
+        assert_eq!(None, profile.diameter_at(600.5));
+    }
+
+    #[test]
+    fn it_finds_the_bore_under_holes() {
+        let profile = profile();
+        let hole = Hole::new("T1", 550.0, 8.0, 4.0).unwrap();
+        assert_eq!(Some(21.0), profile.diameter_under(&hole));
+        let hole = Hole::new("T1", 650.0, 8.0, 4.0).unwrap();
+        assert_eq!(None, profile.diameter_under(&hole));
+    }
+
+    #[test]
+    fn it_splits_into_sections() {
+        let sections = profile().sections();
+        assert_eq!(3, sections.len());
+        assert!(!sections[0].is_cylindrical());
+        assert!(sections[1].is_cylindrical());
+        assert_eq!(350.0, sections[1].length);
+        assert_eq!(500.0, sections[1].end());
+        assert_eq!(11.5, sections[2].end_radius());
+
+        let sections = profile().sections_split_at(&[550.0, 300.0, 150.0, 700.0]);
+        assert_eq!(5, sections.len());
+        assert_eq!(300.0, sections[2].start);
+        assert!(sections[2].is_cylindrical());
+        assert_eq!(21.0, sections[3].end_diameter);
+        assert_eq!(21.0, sections[4].start_diameter);
+        let total: f64 = sections.iter().map(|section| section.length).sum();
+        assert_eq!(600.0, total);
+    }
+}
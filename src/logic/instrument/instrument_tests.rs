@@ -0,0 +1,170 @@
+#[cfg(test)]
+mod instrument_tests {
+    use crate::logic::instrument::bore::{BoreError, BorePoint};
+
+    use super::super::*;
+
+    const DESIGN: &str = r#"{
+        "name": "D whistle",
+        "unit": "millimetre",
+        "bore": [
+            { "position": 0.0, "diameter": 13.0 },
+            { "position": 260.0, "diameter": 12.0 }
+        ],
+        "mouthpiece": {
+            "fipple": {
+                "position": 20.0,
+                "window_length": 5.0,
+                "window_width": 9.0,
+                "windway_height": 1.2
+            }
+        },
+        "termination": "open",
+        "holes": [
+            { "name": "T1", "position": 110.0, "diameter": 7.0, "height": 2.0, "inner_curvature_radius": 0.5 },
+            { "name": "T2", "position": 140.0, "diameter": 6.5, "height": 2.0, "inner_curvature_radius": 0.5 }
+        ]
+    }"#;
+
+    fn whistle() -> Instrument {
+        return serde_json::from_str(DESIGN).unwrap();
+    }
+
+    #[test]
+    fn it_loads_a_design() {
+        let instrument = whistle();
+        assert_eq!("D whistle", instrument.name());
+        assert_eq!(LengthUnit::Millimetre, instrument.unit());
+        assert_eq!(260.0, instrument.bore().length());
+        assert_eq!(20.0, instrument.mouthpiece().position());
+        assert_eq!(&Termination::Open, instrument.termination());
+        assert_eq!(2, instrument.holes().len());
+    }
+
+    #[test]
+    fn it_roundtrips_through_serde() {
+        let instrument = whistle();
+        let json = serde_json::to_string(&instrument).unwrap();
+        assert_eq!(
+            instrument,
+            serde_json::from_str::<Instrument>(&json).unwrap()
+        );
+        let toml = toml::to_string(&instrument).unwrap();
+        assert_eq!(instrument, toml::from_str::<Instrument>(&toml).unwrap());
+    }
+
+    #[test]
+    fn it_converts_units() {
+        let instrument = whistle().to_metres();
+        assert_eq!(LengthUnit::Metre, instrument.unit());
+        assert!((instrument.bore().length() - 0.26).abs() < 1e-12);
+        assert!((instrument.holes()[0].position() - 0.11).abs() < 1e-12);
+
+        let inches = instrument.to_unit(LengthUnit::Inch);
+        assert_eq!("in", inches.unit().to_string());
+        assert!((inches.bore().points()[0].diameter - 13.0 / 25.4).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_validates_the_bore() {
+        let points = vec![BorePoint {
+            position: 0.0,
+            diameter: 19.0,
+        }];
+        assert_eq!(Err(BoreError::TooFewPoints), BoreProfile::new(points));
+        assert_eq!(
+            Err(BoreError::InvalidPoint(0)),
+            BoreProfile::cylinder(600.0, -19.0)
+        );
+        assert_eq!(
+            Err(BoreError::UnorderedPosition(1)),
+            BoreProfile::cylinder(0.0, 19.0)
+        );
+        let design = DESIGN.replace("260.0", "-1.0");
+        assert!(serde_json::from_str::<Instrument>(&design).is_err());
+    }
+
+    #[test]
+    fn it_validates_the_layout() {
+        let instrument = whistle();
+        let bore = instrument.bore().clone();
+        let holes = instrument.holes().to_vec();
+        let build = |mouthpiece: Mouthpiece, termination: Termination, holes: Vec<Hole>| {
+            return Instrument::new(
+                "test",
+                LengthUnit::Millimetre,
+                bore.clone(),
+                mouthpiece,
+                termination,
+                holes,
+            );
+        };
+        let closed = Mouthpiece::Closed { position: 0.0 };
+        assert!(build(closed, Termination::Closed, holes.clone()).is_ok());
+        assert_eq!(
+            Err(InstrumentError::MouthpieceOutsideBore),
+            build(
+                Mouthpiece::Closed { position: 300.0 },
+                Termination::Open,
+                vec![]
+            )
+        );
+        let embouchure = Mouthpiece::Embouchure {
+            position: 10.0,
+            diameter: 0.0,
+            height: 4.0,
+        };
+        assert_eq!(
+            Err(InstrumentError::InvalidMouthpiece),
+            build(embouchure, Termination::Open, vec![])
+        );
+        let flange = Termination::Flanged {
+            flange_diameter: 10.0,
+        };
+        assert_eq!(
+            Err(InstrumentError::InvalidTermination),
+            build(closed, flange, vec![])
+        );
+        let reversed: Vec<Hole> = holes.iter().rev().cloned().collect();
+        assert_eq!(
+            Err(InstrumentError::UnorderedHole(1)),
+            build(closed, Termination::Open, reversed)
+        );
+        let outside = vec![holes[0].scaled(3.0)];
+        assert_eq!(
+            Err(InstrumentError::HoleOutsideBore(0)),
+            build(closed, Termination::Open, outside)
+        );
+    }
+
+    #[test]
+    fn it_splits_the_bore_at_holes() {
+        let instrument = whistle();
+        assert_eq!(
+            vec![12.576923076923077, 12.461538461538462],
+            instrument.hole_bore_diameters()
+        );
+        let sections = instrument.sections();
+        assert_eq!(4, sections.len());
+        assert_eq!(20.0, sections[1].start);
+        assert_eq!(110.0, sections[2].start);
+        assert_eq!(140.0, sections[3].start);
+    }
+
+    #[test]
+    fn it_rejects_holes_wider_than_the_bore() {
+        let instrument = whistle();
+        let hole = Hole::new("T1", 110.0, 13.0, 2.0).unwrap();
+        assert_eq!(
+            Err(InstrumentError::HoleWiderThanBore(0)),
+            Instrument::new(
+                "test",
+                LengthUnit::Millimetre,
+                instrument.bore().clone(),
+                *instrument.mouthpiece(),
+                Termination::Open,
+                vec![hole],
+            )
+        );
+    }
+}
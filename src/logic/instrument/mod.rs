@@ -1 +1,335 @@
+pub mod bore;
 
+use std::{error::Error, fmt};
+
+use serde::{Deserialize, Serialize};
+
+use crate::logic::structs::hole::Hole;
+
+use self::bore::{BoreProfile, BoreSection};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LengthUnit {
+    Millimetre,
+    Centimetre,
+    Metre,
+    Inch,
+}
+
+impl LengthUnit {
+    // Size of one unit, in m.
+    pub fn metres(self) -> f64 {
+        return match self {
+            LengthUnit::Millimetre => 0.001,
+            LengthUnit::Centimetre => 0.01,
+            LengthUnit::Metre => 1.0,
+            LengthUnit::Inch => 0.0254,
+        };
+    }
+}
+
+impl fmt::Display for LengthUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            LengthUnit::Millimetre => "mm",
+            LengthUnit::Centimetre => "cm",
+            LengthUnit::Metre => "m",
+            LengthUnit::Inch => "in",
+        };
+        return write!(f, "{}", symbol);
+    }
+}
+
+// Excitation of the air column, at a position along the bore.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mouthpiece {
+    // Transverse flute embouchure hole, blown across.
+    Embouchure {
+        position: f64,
+        diameter: f64,
+        height: f64, // Lip plate and wall thickness
+    },
+    // Recorder or whistle window fed by a windway.
+    Fipple {
+        position: f64,
+        window_length: f64,
+        window_width: f64,
+        windway_height: f64,
+    },
+    // Reed or lips closing the top of the bore.
+    Closed {
+        position: f64,
+    },
+}
+
+impl Mouthpiece {
+    pub fn position(&self) -> f64 {
+        return match self {
+            Mouthpiece::Embouchure { position, .. }
+            | Mouthpiece::Fipple { position, .. }
+            | Mouthpiece::Closed { position } => *position,
+        };
+    }
+
+    fn dimensions(&self) -> Vec<f64> {
+        return match self {
+            Mouthpiece::Embouchure {
+                diameter, height, ..
+            } => vec![*diameter, *height],
+            Mouthpiece::Fipple {
+                window_length,
+                window_width,
+                windway_height,
+                ..
+            } => vec![*window_length, *window_width, *windway_height],
+            Mouthpiece::Closed { .. } => vec![],
+        };
+    }
+
+    fn scaled(&self, factor: f64) -> Self {
+        return match *self {
+            Mouthpiece::Embouchure {
+                position,
+                diameter,
+                height,
+            } => Mouthpiece::Embouchure {
+                position: position * factor,
+                diameter: diameter * factor,
+                height: height * factor,
+            },
+            Mouthpiece::Fipple {
+                position,
+                window_length,
+                window_width,
+                windway_height,
+            } => Mouthpiece::Fipple {
+                position: position * factor,
+                window_length: window_length * factor,
+                window_width: window_width * factor,
+                windway_height: windway_height * factor,
+            },
+            Mouthpiece::Closed { position } => Mouthpiece::Closed {
+                position: position * factor,
+            },
+        };
+    }
+}
+
+// Boundary at the foot of the bore.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Termination {
+    Open,                             // Unflanged open end
+    Flanged { flange_diameter: f64 }, // Open end with a flange or bell rim
+    Closed,                           // Stopped end
+}
+
+impl Termination {
+    fn scaled(&self, factor: f64) -> Self {
+        return match *self {
+            Termination::Flanged { flange_diameter } => Termination::Flanged {
+                flange_diameter: flange_diameter * factor,
+            },
+            termination => termination,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum InstrumentError {
+    InvalidMouthpiece,        // Mouthpiece with a non-finite or non-positive dimension
+    MouthpieceOutsideBore,    // Mouthpiece position beyond the ends of the bore
+    InvalidTermination,       // Flange not wider than the bore at the foot
+    HoleOutsideBore(usize),   // Index of a hole beyond the ends of the bore
+    HoleWiderThanBore(usize), // Index of a hole wider than the bore under it
+    UnorderedHole(usize),     // Index of the first hole not further down than its predecessor
+}
+
+impl fmt::Display for InstrumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            InstrumentError::InvalidMouthpiece => {
+                write!(f, "mouthpiece dimensions must be finite and positive")
+            }
+            InstrumentError::MouthpieceOutsideBore => {
+                write!(f, "mouthpiece is outside the bore")
+            }
+            InstrumentError::InvalidTermination => {
+                write!(f, "flange must be wider than the foot of the bore")
+            }
+            InstrumentError::HoleOutsideBore(index) => {
+                write!(f, "hole {} is outside the bore", index)
+            }
+            InstrumentError::HoleWiderThanBore(index) => {
+                write!(f, "hole {} is wider than the bore under it", index)
+            }
+            InstrumentError::UnorderedHole(index) => write!(
+                f,
+                "hole {} is not further down the bore than the previous one",
+                index
+            ),
+        };
+    }
+}
+
+impl Error for InstrumentError {}
+
+// Instrument as written in a design file, before validation.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct InstrumentData {
+    name: String,
+    unit: LengthUnit,
+    bore: BoreProfile,
+    mouthpiece: Mouthpiece,
+    termination: Termination,
+    #[serde(default)]
+    holes: Vec<Hole>,
+}
+
+// Named instrument design. All lengths are in the design unit and positions are
+// measured from the top of the bore; holes are ordered from the top down.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "InstrumentData")]
+pub struct Instrument {
+    name: String,
+    unit: LengthUnit,
+    bore: BoreProfile,
+    mouthpiece: Mouthpiece,
+    termination: Termination,
+    holes: Vec<Hole>,
+}
+
+impl Instrument {
+    pub fn new(
+        name: &str,
+        unit: LengthUnit,
+        bore: BoreProfile,
+        mouthpiece: Mouthpiece,
+        termination: Termination,
+        holes: Vec<Hole>,
+    ) -> Result<Self, InstrumentError> {
+        let instrument = Self {
+            name: name.to_string(),
+            unit,
+            bore,
+            mouthpiece,
+            termination,
+            holes,
+        };
+        instrument.validate()?;
+        return Ok(instrument);
+    }
+
+    fn validate(&self) -> Result<(), InstrumentError> {
+        if !self.mouthpiece.position().is_finite()
+            || self
+                .mouthpiece
+                .dimensions()
+                .iter()
+                .any(|value| *value <= 0.0 || !value.is_finite())
+        {
+            return Err(InstrumentError::InvalidMouthpiece);
+        }
+        if !self.bore.contains(self.mouthpiece.position()) {
+            return Err(InstrumentError::MouthpieceOutsideBore);
+        }
+        if let Termination::Flanged { flange_diameter } = self.termination {
+            let foot = self.bore.points()[self.bore.points().len() - 1].diameter;
+            if !flange_diameter.is_finite() || flange_diameter <= foot {
+                return Err(InstrumentError::InvalidTermination);
+            }
+        }
+        for (index, hole) in self.holes.iter().enumerate() {
+            let bore_diameter = self
+                .bore
+                .diameter_under(hole)
+                .ok_or(InstrumentError::HoleOutsideBore(index))?;
+            if hole.diameter() > bore_diameter {
+                return Err(InstrumentError::HoleWiderThanBore(index));
+            }
+            if index > 0 && hole.position() <= self.holes[index - 1].position() {
+                return Err(InstrumentError::UnorderedHole(index));
+            }
+        }
+        return Ok(());
+    }
+
+    pub fn name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn unit(&self) -> LengthUnit {
+        return self.unit;
+    }
+
+    pub fn bore(&self) -> &BoreProfile {
+        return &self.bore;
+    }
+
+    pub fn mouthpiece(&self) -> &Mouthpiece {
+        return &self.mouthpiece;
+    }
+
+    pub fn termination(&self) -> &Termination {
+        return &self.termination;
+    }
+
+    pub fn holes(&self) -> &[Hole] {
+        return &self.holes;
+    }
+
+    // Bore diameter under each hole, in hole order.
+    pub fn hole_bore_diameters(&self) -> Vec<f64> {
+        return self
+            .holes
+            .iter()
+            .filter_map(|hole| self.bore.diameter_under(hole))
+            .collect();
+    }
+
+    // Bore sections from top to foot, split at the mouthpiece and at every hole.
+    pub fn sections(&self) -> Vec<BoreSection> {
+        let mut positions = vec![self.mouthpiece.position()];
+        positions.extend(self.holes.iter().map(|hole| hole.position()));
+        return self.bore.sections_split_at(&positions);
+    }
+
+    // Same design with its lengths expressed in another unit.
+    pub fn to_unit(&self, unit: LengthUnit) -> Self {
+        let factor = self.unit.metres() / unit.metres();
+        return Self {
+            name: self.name.clone(),
+            unit,
+            bore: self.bore.scaled(factor),
+            mouthpiece: self.mouthpiece.scaled(factor),
+            termination: self.termination.scaled(factor),
+            holes: self.holes.iter().map(|hole| hole.scaled(factor)).collect(),
+        };
+    }
+
+    // Same design in m, the unit of every acoustic calculation.
+    pub fn to_metres(&self) -> Self {
+        return self.to_unit(LengthUnit::Metre);
+    }
+}
+
+impl TryFrom<InstrumentData> for Instrument {
+    type Error = InstrumentError;
+
+    fn try_from(data: InstrumentData) -> Result<Self, Self::Error> {
+        return Self::new(
+            &data.name,
+            data.unit,
+            data.bore,
+            data.mouthpiece,
+            data.termination,
+            data.holes,
+        );
+    }
+}
+
+#[cfg(test)]
+mod instrument_tests;
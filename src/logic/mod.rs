@@ -1 +1,4 @@
+pub mod acoustics;
+pub mod instrument;
 pub mod physics;
+pub mod structs;
@@ -0,0 +1,50 @@
+use super::UNIVERSAL_GAS_CONSTANT;
+
+// International Standard Atmosphere, sea level and troposphere values.
+pub const STANDARD_PRESSURE: f64 = 101.325; // kPa
+pub const STANDARD_TEMPERATURE: f64 = 288.15; // K
+pub const STANDARD_LAPSE_RATE: f64 = 0.0065; // K/m
+pub const STANDARD_GRAVITY: f64 = 9.80665; // m/s^2
+pub const STANDARD_MOLAR_MASS: f64 = 28.9644; // kg/kmol
+pub const TROPOPAUSE_ALTITUDE: f64 = 11000.0; // m
+
+fn barometric_exponent() -> f64 {
+    // g.M/(R.L), with the molar mass converted from kg/kmol to kg/mol.
+    return STANDARD_GRAVITY * STANDARD_MOLAR_MASS * 1e-3
+        / (UNIVERSAL_GAS_CONSTANT * STANDARD_LAPSE_RATE);
+}
+
+// Air pressure in kPa at an altitude in metres above sea level, using the ISA.
+pub fn pressure_at(altitude: f64) -> f64 {
+    return pressure_at_from_sea_level(altitude, STANDARD_PRESSURE);
+}
+
+// Air pressure in kPa at an altitude in metres, given the local pressure
+// reduced to sea level (QNH) in kPa, as quoted in weather reports.
+pub fn pressure_at_from_sea_level(altitude: f64, sea_level_pressure: f64) -> f64 {
+    let troposphere_altitude = altitude.min(TROPOPAUSE_ALTITUDE);
+    let pressure = sea_level_pressure
+        * (1.0 - STANDARD_LAPSE_RATE * troposphere_altitude / STANDARD_TEMPERATURE)
+            .powf(barometric_exponent());
+    if altitude <= TROPOPAUSE_ALTITUDE {
+        return pressure;
+    }
+    // The lower stratosphere is isothermal.
+    let tropopause_temperature = STANDARD_TEMPERATURE - STANDARD_LAPSE_RATE * TROPOPAUSE_ALTITUDE;
+    return pressure
+        * (-STANDARD_GRAVITY * STANDARD_MOLAR_MASS * 1e-3 * (altitude - TROPOPAUSE_ALTITUDE)
+            / (UNIVERSAL_GAS_CONSTANT * tropopause_temperature))
+            .exp();
+}
+
+// Isothermal barometric formula at the ISA sea-level temperature.
+// This is the model behind the altitude figures of the Java WWIDesigner.
+pub fn isothermal_pressure_at(altitude: f64, sea_level_pressure: f64) -> f64 {
+    return sea_level_pressure
+        * (-STANDARD_GRAVITY * STANDARD_MOLAR_MASS * 1e-3 * altitude
+            / (UNIVERSAL_GAS_CONSTANT * STANDARD_TEMPERATURE))
+            .exp();
+}
+
+#[cfg(test)]
+mod atmosphere_tests;
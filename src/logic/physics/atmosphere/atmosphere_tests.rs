@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod atmosphere_tests {
+    use super::super::*;
+
+    #[test]
+    fn it_returns_standard_pressure_at_sea_level() {
+        assert_eq!(STANDARD_PRESSURE, pressure_at(0.0));
+        assert_eq!(102.1, pressure_at_from_sea_level(0.0, 102.1));
+    }
+
+    #[test]
+    fn it_follows_the_standard_atmosphere() {
+        assert!((pressure_at(1000.0) - 89.875).abs() < 0.001);
+        assert!((pressure_at(3000.0) - 70.109).abs() < 0.001);
+        assert!((pressure_at(11000.0) - 22.632).abs() < 0.001);
+        assert!((pressure_at(15000.0) - 12.045).abs() < 0.001);
+    }
+
+    #[test]
+    fn it_scales_with_local_sea_level_pressure() {
+        let standard = pressure_at(2500.0);
+        let actual = pressure_at_from_sea_level(2500.0, 2.0 * STANDARD_PRESSURE);
+        assert!((actual - 2.0 * standard).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_matches_the_java_isothermal_model() {
+        let actual = isothermal_pressure_at(1000.0, STANDARD_PRESSURE);
+        assert!((actual - 89.996).abs() < 0.001);
+    }
+}
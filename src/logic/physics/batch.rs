@@ -0,0 +1,148 @@
+use num_complex::Complex64;
+
+use crate::structs::parameters::PhysicalParameters;
+
+use super::losses::ViscothermalLossModel;
+
+// Propagation constants and characteristic impedances of one bore radius over a frequency grid.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoreSpectrum {
+    pub radius: f64,                               // Nominal bore radius in m
+    pub propagation_constants: Vec<Complex64>,     // Gamma in 1/m, one per frequency
+    pub characteristic_impedances: Vec<Complex64>, // Zc in kg/(m^4.s), one per frequency
+}
+
+// Frequency-grid versions of the scalar helpers. The *_into variants write into a
+// caller-owned buffer of the same length as the input, to avoid allocating in sweeps.
+impl PhysicalParameters {
+    pub fn wave_numbers_into(&self, frequencies: &[f64], output: &mut [f64]) {
+        assert_eq!(frequencies.len(), output.len());
+        for (output, frequency) in output.iter_mut().zip(frequencies) {
+            *output = frequency * self.wave_number;
+        }
+    }
+
+    pub fn wave_numbers(&self, frequencies: &[f64]) -> Vec<f64> {
+        let mut output = vec![0.0; frequencies.len()];
+        self.wave_numbers_into(frequencies, &mut output);
+        return output;
+    }
+
+    pub fn epsilons_into(&self, frequencies: &[f64], radius: f64, output: &mut [f64]) {
+        assert_eq!(frequencies.len(), output.len());
+        let constant = self.epsilon_constant / radius;
+        for (output, frequency) in output.iter_mut().zip(frequencies) {
+            *output = constant / frequency.sqrt();
+        }
+    }
+
+    pub fn epsilons(&self, frequencies: &[f64], radius: f64) -> Vec<f64> {
+        let mut output = vec![0.0; frequencies.len()];
+        self.epsilons_into(frequencies, radius, &mut output);
+        return output;
+    }
+
+    pub fn wave_impedances_into(&self, radii: &[f64], output: &mut [f64]) {
+        assert_eq!(radii.len(), output.len());
+        for (output, radius) in output.iter_mut().zip(radii) {
+            *output = self.wave_impedance(*radius);
+        }
+    }
+
+    pub fn wave_impedances(&self, radii: &[f64]) -> Vec<f64> {
+        let mut output = vec![0.0; radii.len()];
+        self.wave_impedances_into(radii, &mut output);
+        return output;
+    }
+
+    pub fn propagation_constants_into(
+        &self,
+        frequencies: &[f64],
+        radius: f64,
+        output: &mut [Complex64],
+    ) {
+        assert_eq!(frequencies.len(), output.len());
+        for (output, frequency) in output.iter_mut().zip(frequencies) {
+            *output = self.propagation_constant(*frequency, radius);
+        }
+    }
+
+    pub fn propagation_constants(&self, frequencies: &[f64], radius: f64) -> Vec<Complex64> {
+        let mut output = vec![Complex64::default(); frequencies.len()];
+        self.propagation_constants_into(frequencies, radius, &mut output);
+        return output;
+    }
+
+    pub fn characteristic_impedances_into(
+        &self,
+        frequencies: &[f64],
+        radius: f64,
+        output: &mut [Complex64],
+    ) {
+        assert_eq!(frequencies.len(), output.len());
+        for (output, frequency) in output.iter_mut().zip(frequencies) {
+            *output = self.characteristic_impedance(*frequency, radius);
+        }
+    }
+
+    pub fn characteristic_impedances(&self, frequencies: &[f64], radius: f64) -> Vec<Complex64> {
+        let mut output = vec![Complex64::default(); frequencies.len()];
+        self.characteristic_impedances_into(frequencies, radius, &mut output);
+        return output;
+    }
+
+    // Both lossy quantities for every radius, sharing the loss factor between them.
+    pub fn bore_spectra(&self, frequencies: &[f64], radii: &[f64]) -> Vec<BoreSpectrum> {
+        let wave_numbers = self.wave_numbers(frequencies);
+        let root_wave_numbers: Vec<f64> = wave_numbers.iter().map(|k| k.sqrt()).collect();
+        return radii
+            .iter()
+            .map(|radius| {
+                let impedance = self.wave_impedance(*radius);
+                let constant = self.alpha_constant / radius;
+                let (propagation_constants, characteristic_impedances) = wave_numbers
+                    .iter()
+                    .zip(&root_wave_numbers)
+                    .map(|(wave_number, root_wave_number)| {
+                        let epsilon = constant / root_wave_number;
+                        return (
+                            Complex64::new(wave_number * epsilon, wave_number * (1.0 + epsilon)),
+                            Complex64::new(impedance * (1.0 + epsilon), -impedance * epsilon),
+                        );
+                    })
+                    .unzip();
+                return BoreSpectrum {
+                    radius: *radius,
+                    propagation_constants,
+                    characteristic_impedances,
+                };
+            })
+            .collect();
+    }
+
+    // Same as bore_spectra, with the losses of any viscothermal loss model.
+    pub fn bore_spectra_with(
+        &self,
+        frequencies: &[f64],
+        radii: &[f64],
+        model: &dyn ViscothermalLossModel,
+    ) -> Vec<BoreSpectrum> {
+        return radii
+            .iter()
+            .map(|radius| BoreSpectrum {
+                radius: *radius,
+                propagation_constants: frequencies
+                    .iter()
+                    .map(|frequency| model.propagation_constant(self, *frequency, *radius))
+                    .collect(),
+                characteristic_impedances: frequencies
+                    .iter()
+                    .map(|frequency| model.characteristic_impedance(self, *frequency, *radius))
+                    .collect(),
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod batch_tests;
@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod batch_tests {
+    use crate::{
+        logic::physics::losses::{LossModel, ViscothermalLossModel, ZwikkerKosten},
+        structs::parameters::ParametersBuilder,
+    };
+
+    fn frequencies() -> Vec<f64> {
+        return (1..=2000).map(|index| index as f64).collect();
+    }
+
+    #[test]
+    fn it_matches_the_scalar_helpers() {
+        let parameters = ParametersBuilder::new().build();
+        let frequencies = frequencies();
+
+        let wave_numbers = parameters.wave_numbers(&frequencies);
+        let epsilons = parameters.epsilons(&frequencies, 0.008);
+        let gammas = parameters.propagation_constants(&frequencies, 0.008);
+        let impedances = parameters.characteristic_impedances(&frequencies, 0.008);
+        for (index, frequency) in frequencies.iter().enumerate() {
+            assert_eq!(parameters.wave_number(*frequency), wave_numbers[index]);
+            let epsilon = parameters.get_epsilon_from_f(*frequency, 0.008);
+            assert!((epsilons[index] - epsilon).abs() < 1e-15);
+            assert_eq!(
+                parameters.propagation_constant(*frequency, 0.008),
+                gammas[index]
+            );
+            assert_eq!(
+                parameters.characteristic_impedance(*frequency, 0.008),
+                impedances[index]
+            );
+        }
+
+        let radii = [0.004, 0.008];
+        let actual = parameters.wave_impedances(&radii);
+        assert_eq!(parameters.wave_impedance(0.004), actual[0]);
+    }
+
+    #[test]
+    fn it_computes_spectra_for_several_radii() {
+        let parameters = ParametersBuilder::new().build();
+        let frequencies = frequencies();
+        let spectra = parameters.bore_spectra(&frequencies, &[0.004, 0.008, 0.012]);
+
+        assert_eq!(3, spectra.len());
+        for spectrum in &spectra {
+            assert_eq!(frequencies.len(), spectrum.propagation_constants.len());
+            let gamma = parameters.propagation_constant(440.0, spectrum.radius);
+            assert!((spectrum.propagation_constants[439] - gamma).norm() < 1e-12);
+            let impedance = parameters.characteristic_impedance(440.0, spectrum.radius);
+            let actual = spectrum.characteristic_impedances[439];
+            assert!((actual - impedance).norm() / impedance.norm() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn it_requires_matching_buffers() {
+        let parameters = ParametersBuilder::new().build();
+        let mut output = vec![0.0; 3];
+        parameters.wave_numbers_into(&[1.0, 2.0], &mut output);
+    }
+
+    #[test]
+    fn it_evaluates_any_loss_model() {
+        let parameters = ParametersBuilder::new().build();
+        let frequencies = frequencies();
+        let radii = [0.0005, 0.008];
+        let keefe =
+            parameters.bore_spectra_with(&frequencies, &radii, LossModel::Keefe1984.model());
+        assert_eq!(
+            parameters.bore_spectra(&frequencies, &radii).len(),
+            keefe.len()
+        );
+        let exact = parameters.bore_spectra_with(&frequencies, &radii, &ZwikkerKosten);
+        for (spectrum, radius) in exact.iter().zip(radii) {
+            assert_eq!(radius, spectrum.radius);
+            assert_eq!(
+                ZwikkerKosten.propagation_constant(&parameters, 440.0, radius),
+                spectrum.propagation_constants[439]
+            );
+        }
+    }
+}
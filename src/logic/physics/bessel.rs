@@ -0,0 +1,61 @@
+use num_complex::Complex64;
+
+// Below this modulus the power series is used; above it, Hankel's asymptotic expansion.
+const SERIES_LIMIT: f64 = 20.0;
+const MAX_TERMS: usize = 200;
+
+// Bessel functions of the first kind J0 and J1 of a complex argument, by power series.
+// Accurate for moderate |z|; prefer bessel_j1_over_j0 for the ratio at large |z|.
+pub fn bessel_j0_j1(z: Complex64) -> (Complex64, Complex64) {
+    let quarter_square = -0.25 * z * z;
+    let mut term_j0 = Complex64::new(1.0, 0.0);
+    let mut term_j1 = 0.5 * z;
+    let (mut j0, mut j1) = (term_j0, term_j1);
+    for k in 1..MAX_TERMS {
+        let k = k as f64;
+        term_j0 *= quarter_square / (k * k);
+        term_j1 *= quarter_square / (k * (k + 1.0));
+        j0 += term_j0;
+        j1 += term_j1;
+        if term_j0.norm() <= f64::EPSILON * j0.norm() && term_j1.norm() <= f64::EPSILON * j1.norm()
+        {
+            break;
+        }
+    }
+    return (j0, j1);
+}
+
+// Hankel's asymptotic P and Q series for J_nu, combined as P + jQ.
+fn hankel_series(nu: f64, z: Complex64) -> Complex64 {
+    let mu = 4.0 * nu * nu;
+    let mut term = Complex64::new(1.0, 0.0);
+    let mut sum = term;
+    for k in 1..MAX_TERMS {
+        let odd = (2 * k - 1) as f64;
+        let next = term * (mu - odd * odd) / (k as f64 * 8.0 * z) * Complex64::i();
+        if next.norm() >= term.norm() {
+            break;
+        }
+        term = next;
+        sum += term;
+        if term.norm() <= f64::EPSILON * sum.norm() {
+            break;
+        }
+    }
+    return sum;
+}
+
+// Ratio J1(z)/J0(z), stable for any z in the lower half plane, where both
+// functions grow like exp(|Im z|) and overflow long before their ratio does.
+pub fn bessel_j1_over_j0(z: Complex64) -> Complex64 {
+    if z.norm() <= SERIES_LIMIT || z.im > 0.0 {
+        let (j0, j1) = bessel_j0_j1(z);
+        return j1 / j0;
+    }
+    // With Im z << 0, J_nu ~ sqrt(2/(pi z)) exp(j(z - nu.pi/2 - pi/4)) (P + jQ) / 2,
+    // so the exponential factors cancel in the ratio, leaving exp(-j.pi/2).
+    return -Complex64::i() * hankel_series(1.0, z) / hankel_series(0.0, z);
+}
+
+#[cfg(test)]
+mod bessel_tests;
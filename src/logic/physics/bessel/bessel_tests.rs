@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod bessel_tests {
+    use num_complex::Complex64;
+
+    use super::super::*;
+
+    fn assert_close(expected: Complex64, actual: Complex64) {
+        assert!(
+            (actual - expected).norm() <= 1e-12 * expected.norm(),
+            "{} differs from {}",
+            actual,
+            expected
+        );
+    }
+
+    #[test]
+    fn it_matches_reference_values() {
+        let (j0, j1) = bessel_j0_j1(Complex64::new(1.0, 0.0));
+        assert_close(Complex64::new(0.765197686557967, 0.0), j0);
+        assert_close(Complex64::new(0.440050585744933, 0.0), j1);
+
+        let (j0, j1) = bessel_j0_j1(Complex64::new(10.0, -10.0));
+        assert_close(Complex64::new(-2314.97531444521, -411.562857025381), j0);
+        assert_close(Complex64::new(-460.680913538353, 2246.62679070406), j1);
+    }
+
+    #[test]
+    fn it_computes_the_ratio_at_any_size() {
+        let actual = bessel_j1_over_j0(Complex64::new(2.12132034355964, -2.12132034355964));
+        assert_close(
+            Complex64::new(0.147482101179883, -0.911078594735893),
+            actual,
+        );
+
+        let actual = bessel_j1_over_j0(Complex64::new(30.0, -30.0));
+        assert_close(
+            Complex64::new(0.00840393290663645, -0.99166788643511),
+            actual,
+        );
+
+        // Far beyond where J0 and J1 overflow, the ratio tends to -j.
+        let actual = bessel_j1_over_j0(Complex64::new(3000.0, -3000.0));
+        assert!((actual + Complex64::i()).norm() < 1e-3);
+    }
+}
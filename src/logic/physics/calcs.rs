@@ -1,5 +1,6 @@
 use std::f64::consts::PI;
 
+use super::models::AirPropertyModel;
 use super::temperature::kelvin_to_celsius;
 use super::{
     MOLAR_MASS_CO2, MOLAR_MASS_DRY_AIR, MOLAR_MASS_O2, MOLAR_MASS_WATER_VAPOUR,
@@ -28,22 +29,131 @@ pub fn temperature_to_air_dynamic_viscosity(temperature: f64) -> f64 {
     return 1.4592e-6 * temperature.powf(1.5) / (temperature + 109.1);
 }
 
+// Air properties derived from one set of inputs, with every intermediate value
+// computed once, so that building parameters does not repeat the same work.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirState {
+    // Inputs
+    pub pressure: f64,            // Air Pressure in kPa
+    pub temperature: f64,         // Temperature in Kelvin degrees
+    pub humidity_saturation: f64, // % of saturation
+    pub molar_co2: f64,           // Molar fraction of CO2 in mol/mol
+    // Composition
+    pub molar_water_vapour: f64, // Molar fraction of water vapour in mol/mol
+    pub humidity_ratio: f64,     // Moles of water vapour per mole of dry air
+    pub molar_mass_dry_air: f64, // kg/kmol
+    pub molar_mass_moist_air: f64, // kg/kmol
+    pub humid_air_constant: f64, // Specific gas constant of the moist air, J/(kg.K)
+    // Derived properties
+    pub air_density: f64,
+    pub dynamic_viscosity: f64,
+    pub specific_heat: f64,
+    pub specific_heats_ratio: f64,
+    pub thermal_conductivity: f64,
+    pub prandtl_number: f64,
+    pub sound_speed: f64,
+    pub epsilon_constant: f64,
+    pub alpha_constant: f64,
+    pub wave_number: f64,
+}
+
+impl AirState {
+    pub fn new(pressure: f64, temperature: f64, humidity_saturation: f64, molar_co2: f64) -> Self {
+        let molar_water_vapour =
+            calculate_molar_water_vapour(pressure, temperature, humidity_saturation);
+        let humidity_ratio = molar_water_vapour_to_humidity_ratio(molar_water_vapour);
+        let molar_mass_dry_air = molar_co2_to_mass_dry_air(molar_co2);
+        let molar_mass_moist_air = calculate_mass_moist_air(molar_water_vapour, molar_mass_dry_air);
+        let humid_air_constant = calculate_humid_air_constant(molar_mass_moist_air);
+
+        let air_density = air_density_from_composition(
+            pressure,
+            temperature,
+            molar_water_vapour,
+            calculate_humid_air_constant(molar_mass_dry_air),
+        );
+
+        let air_dynamic_viscosity = temperature_to_air_dynamic_viscosity(temperature);
+        let water_vapour_dynamic_viscosity =
+            temperature_to_water_vapour_dynamic_viscosity(temperature);
+        let viscosity_ratio =
+            dynamic_viscosity_ratio(air_dynamic_viscosity, water_vapour_dynamic_viscosity);
+        let phi_air_vapour = calculate_phi_air_vapour(viscosity_ratio, molar_mass_dry_air);
+        let phi_vapour_air = calculate_phi_vapour_air(viscosity_ratio, molar_mass_dry_air);
+
+        let dynamic_viscosity = mix_with_water_vapour(
+            air_dynamic_viscosity,
+            water_vapour_dynamic_viscosity,
+            humidity_ratio,
+            phi_air_vapour,
+            phi_vapour_air,
+        );
+        let specific_heat = specific_heat_from_composition(
+            temperature,
+            molar_water_vapour,
+            molar_co2,
+            molar_mass_moist_air,
+        );
+        let specific_heats_ratio = specific_heat / (specific_heat - humid_air_constant);
+        let thermal_conductivity = mix_with_water_vapour(
+            temperature_to_air_thermal_conductivity(temperature),
+            temperature_to_water_vapour_thermal_conductivity(temperature),
+            humidity_ratio,
+            phi_air_vapour,
+            phi_vapour_air,
+        );
+        let prandtl_number = dynamic_viscosity * specific_heat / thermal_conductivity;
+        let sound_speed = (specific_heats_ratio * humid_air_constant * temperature).sqrt();
+        let loss_factor = 1.0 + (specific_heats_ratio - 1.0) / prandtl_number.sqrt();
+
+        return Self {
+            pressure,
+            temperature,
+            humidity_saturation,
+            molar_co2,
+            molar_water_vapour,
+            humidity_ratio,
+            molar_mass_dry_air,
+            molar_mass_moist_air,
+            humid_air_constant,
+            air_density,
+            dynamic_viscosity,
+            specific_heat,
+            specific_heats_ratio,
+            thermal_conductivity,
+            prandtl_number,
+            sound_speed,
+            epsilon_constant: 1.0 / (2.0 * PI.sqrt())
+                * (dynamic_viscosity / air_density).sqrt()
+                * loss_factor,
+            alpha_constant: (dynamic_viscosity / (2.0 * air_density * sound_speed)).sqrt()
+                * loss_factor,
+            wave_number: 2.0 * PI / sound_speed,
+        };
+    }
+
+    // Same state with the sound speed given by another model, and the constants derived from it.
+    pub fn with_model(self, model: &dyn AirPropertyModel) -> Self {
+        let sound_speed = model.sound_speed(&self);
+        let loss_factor = 1.0 + (self.specific_heats_ratio - 1.0) / self.prandtl_number.sqrt();
+        return Self {
+            sound_speed,
+            alpha_constant: (self.dynamic_viscosity / (2.0 * self.air_density * sound_speed))
+                .sqrt()
+                * loss_factor,
+            wave_number: 2.0 * PI / sound_speed,
+            ..self
+        };
+    }
+}
+
 pub fn calculate_epsilon_constant(
     pressure: f64,
     temperature: f64,
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let specific_heats_ratio =
-        calculate_specific_heats_ratio(pressure, temperature, humidity_saturation, molar_co2);
-    let prandtl_number =
-        calculate_prandtl_number(pressure, temperature, humidity_saturation, molar_co2);
-    let dynamic_viscosity =
-        calculate_dynamic_viscosity(pressure, temperature, humidity_saturation, molar_co2);
-    let air_density = calculate_air_density(pressure, temperature, humidity_saturation, molar_co2);
-    return 1.0 / (2.0 * PI.sqrt())
-        * (dynamic_viscosity / air_density).sqrt()
-        * (1.0 + (specific_heats_ratio - 1.0) / prandtl_number.sqrt());
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).epsilon_constant;
 }
 
 pub fn calculate_alpha_constant(
@@ -52,16 +162,7 @@ pub fn calculate_alpha_constant(
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let sound_speed = calculate_sound_speed(pressure, temperature, humidity_saturation, molar_co2);
-    let specific_heats_ratio =
-        calculate_specific_heats_ratio(pressure, temperature, humidity_saturation, molar_co2);
-    let prandtl_number =
-        calculate_prandtl_number(pressure, temperature, humidity_saturation, molar_co2);
-    let dynamic_viscosity =
-        calculate_dynamic_viscosity(pressure, temperature, humidity_saturation, molar_co2);
-    let air_density = calculate_air_density(pressure, temperature, humidity_saturation, molar_co2);
-    return (dynamic_viscosity / (2.0 * air_density * sound_speed)).sqrt()
-        * (1.0 + (specific_heats_ratio - 1.0) / prandtl_number.sqrt());
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).alpha_constant;
 }
 
 pub fn dynamic_viscosity_ratio(
@@ -77,8 +178,7 @@ pub fn sound_speed_to_wave_number(
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let sound_speed = calculate_sound_speed(pressure, temperature, humidity_saturation, molar_co2);
-    return 2.0 * PI / sound_speed;
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).wave_number;
 }
 
 pub fn temperature_to_water_vapour_dynamic_viscosity(temperature: f64) -> f64 {
@@ -118,15 +218,12 @@ pub fn calculate_molar_water_vapour(
     return 0.01 * humidity_saturation * enhancement_factor * saturated_vapour_pressure / pressure;
 }
 
-pub fn calculate_air_density(
+pub fn air_density_from_composition(
     pressure: f64,
     temperature: f64,
-    humidity_saturation: f64,
-    molar_co2: f64,
+    molar_water_vapour: f64,
+    humid_air_constant: f64,
 ) -> f64 {
-    let humid_air_constant = calculate_humid_air_constant(molar_co2_to_mass_dry_air(molar_co2));
-    let molar_water_vapour =
-        calculate_molar_water_vapour(pressure, temperature, humidity_saturation);
     let pascal_pressure = pressure * 1000.0;
     let compressibility = 1.0
         - pascal_pressure / temperature
@@ -139,6 +236,27 @@ pub fn calculate_air_density(
     return pressure * 1e3 / (compressibility * humid_air_constant * temperature);
 }
 
+pub fn calculate_air_density(
+    pressure: f64,
+    temperature: f64,
+    humidity_saturation: f64,
+    molar_co2: f64,
+) -> f64 {
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).air_density;
+}
+
+// Mixing rule for the viscosity and conductivity of moist air, from Tsilingiris, 2007.
+pub fn mix_with_water_vapour(
+    air_property: f64,
+    water_vapour_property: f64,
+    humidity_ratio: f64,
+    phi_air_vapour: f64,
+    phi_vapour_air: f64,
+) -> f64 {
+    return air_property / (1.0 + phi_air_vapour * humidity_ratio)
+        + humidity_ratio * water_vapour_property / (humidity_ratio + phi_vapour_air);
+}
+
 // Dynamic viscosity,
 pub fn calculate_dynamic_viscosity(
     pressure: f64,
@@ -146,29 +264,7 @@ pub fn calculate_dynamic_viscosity(
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let air_dynamic_viscosity = temperature_to_air_dynamic_viscosity(temperature);
-    let water_vapour_dynamic_viscosity = temperature_to_water_vapour_dynamic_viscosity(temperature);
-    let humidity_ratio = molar_water_vapour_to_humidity_ratio(calculate_molar_water_vapour(
-        pressure,
-        temperature,
-        humidity_saturation,
-    ));
-    let phi_air_vapour = calculate_phi_air_vapour(
-        dynamic_viscosity_ratio(
-            temperature_to_air_dynamic_viscosity(temperature),
-            temperature_to_water_vapour_dynamic_viscosity(temperature),
-        ),
-        molar_co2_to_mass_dry_air(molar_co2),
-    );
-    let phi_vapour_air = calculate_phi_vapour_air(
-        dynamic_viscosity_ratio(
-            temperature_to_air_dynamic_viscosity(temperature),
-            temperature_to_water_vapour_dynamic_viscosity(temperature),
-        ),
-        molar_co2_to_mass_dry_air(molar_co2),
-    );
-    return air_dynamic_viscosity / (1.0 + phi_air_vapour * humidity_ratio)
-        + humidity_ratio * water_vapour_dynamic_viscosity / (humidity_ratio + phi_vapour_air);
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).dynamic_viscosity;
 }
 
 pub fn calculate_phi_air_vapour(dynamic_viscosity_ratio: f64, molar_mass_dry_air: f64) -> f64 {
@@ -187,21 +283,17 @@ pub fn calculate_phi_vapour_air(dynamic_viscosity_ratio: f64, molar_mass_dry_air
         / (2.0 * (1.0 + (MOLAR_MASS_WATER_VAPOUR / molar_mass_dry_air))).sqrt();
 }
 
-pub fn calculate_specific_heat(
-    pressure: f64,
+pub fn specific_heat_from_composition(
     temperature: f64,
-    humidity_saturation: f64,
+    molar_water_vapour: f64,
     molar_co2: f64,
+    molar_mass_moist_air: f64,
 ) -> f64 {
     // Isobaric specific heat, cp, in J/(kg.K).
 
     // Isobaric specific heat of air and water vapour, from Tsilingiris,
     // 2007,
     // with specific heat of air reduced by 2 J/kg.K to get gamma correct.
-    let molar_water_vapour =
-        calculate_molar_water_vapour(pressure, temperature, humidity_saturation);
-    let molar_mass_dry_air = molar_co2_to_mass_dry_air(molar_co2);
-    let molar_mass_moist_air = calculate_mass_moist_air(molar_water_vapour, molar_mass_dry_air);
     let mass_fraction_water_vapour =
         calculate_mass_water_vapour(molar_water_vapour, molar_mass_moist_air);
     let mass_fraction_co2 = calculate_mass_fraction_co2(molar_co2, molar_mass_moist_air);
@@ -220,56 +312,47 @@ pub fn calculate_specific_heat(
         + co2_specific_heat * mass_fraction_co2;
 }
 
-pub fn calculate_specific_heats_ratio(
+pub fn calculate_specific_heat(
     pressure: f64,
     temperature: f64,
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let specific_heat =
-        calculate_specific_heat(pressure, temperature, humidity_saturation, molar_co2);
-    let humid_air_constant = calculate_humid_air_constant(calculate_mass_moist_air(
-        calculate_molar_water_vapour(pressure, temperature, humidity_saturation),
-        molar_co2_to_mass_dry_air(molar_co2),
-    ));
-    return specific_heat / (specific_heat - humid_air_constant);
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).specific_heat;
 }
 
-// Thermal conductivity, in W/(m.K).
-pub fn calculate_thermal_conductivity(
+pub fn calculate_specific_heats_ratio(
     pressure: f64,
     temperature: f64,
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2)
+        .specific_heats_ratio;
+}
+
+pub fn temperature_to_air_thermal_conductivity(temperature: f64) -> f64 {
     // Thermal conductivity of dry air, using Sutherland's formula, from
     // McQuillan, et al., 1984.
-    let humidity_ratio = molar_water_vapour_to_humidity_ratio(calculate_molar_water_vapour(
-        pressure,
-        temperature,
-        humidity_saturation,
-    ));
-    let phi_air_vapour = calculate_phi_air_vapour(
-        dynamic_viscosity_ratio(
-            temperature_to_air_dynamic_viscosity(temperature),
-            temperature_to_water_vapour_dynamic_viscosity(temperature),
-        ),
-        molar_co2_to_mass_dry_air(molar_co2),
-    );
-    let phi_vapour_air = calculate_phi_vapour_air(
-        dynamic_viscosity_ratio(
-            temperature_to_air_dynamic_viscosity(temperature),
-            temperature_to_water_vapour_dynamic_viscosity(temperature),
-        ),
-        molar_co2_to_mass_dry_air(molar_co2),
-    );
-    let celsius_temperature = kelvin_to_celsius(temperature);
-    let kappa_air = 2.3340e-3 * temperature.powf(1.5) / (temperature + 164.54);
+    return 2.3340e-3 * temperature.powf(1.5) / (temperature + 164.54);
+}
+
+pub fn temperature_to_water_vapour_thermal_conductivity(temperature: f64) -> f64 {
     // Thermal conductivity of water vapour, from Tsirilingis, 2007.
-    let kappa_vapour = 0.01761758242
+    let celsius_temperature = kelvin_to_celsius(temperature);
+    return 0.01761758242
         + celsius_temperature * (5.558941059e-5 + celsius_temperature * 1.663336663e-7);
-    return kappa_air / (1.0 + phi_air_vapour * humidity_ratio)
-        + humidity_ratio * kappa_vapour / (humidity_ratio + phi_vapour_air);
+}
+
+// Thermal conductivity, in W/(m.K).
+pub fn calculate_thermal_conductivity(
+    pressure: f64,
+    temperature: f64,
+    humidity_saturation: f64,
+    molar_co2: f64,
+) -> f64 {
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2)
+        .thermal_conductivity;
 }
 
 pub fn calculate_prandtl_number(
@@ -278,13 +361,7 @@ pub fn calculate_prandtl_number(
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let dynamic_viscosity =
-        calculate_dynamic_viscosity(pressure, temperature, humidity_saturation, molar_co2);
-    let specific_heat =
-        calculate_specific_heat(pressure, temperature, humidity_saturation, molar_co2);
-    let thermal_conductivity =
-        calculate_thermal_conductivity(pressure, temperature, humidity_saturation, molar_co2);
-    return dynamic_viscosity * specific_heat / thermal_conductivity;
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).prandtl_number;
 }
 
 pub fn calculate_sound_speed(
@@ -293,11 +370,8 @@ pub fn calculate_sound_speed(
     humidity_saturation: f64,
     molar_co2: f64,
 ) -> f64 {
-    let specific_heats_ratio =
-        calculate_specific_heats_ratio(pressure, temperature, humidity_saturation, molar_co2);
-    let humid_air_constant = calculate_humid_air_constant(calculate_mass_moist_air(
-        calculate_molar_water_vapour(pressure, temperature, humidity_saturation),
-        molar_co2_to_mass_dry_air(molar_co2),
-    ));
-    return (specific_heats_ratio * humid_air_constant * temperature).sqrt();
+    return AirState::new(pressure, temperature, humidity_saturation, molar_co2).sound_speed;
 }
+
+#[cfg(test)]
+mod calcs_tests;
@@ -0,0 +1,42 @@
+#[cfg(test)]
+mod calcs_tests {
+    use super::super::*;
+
+    #[test]
+    fn it_computes_the_same_values_as_the_individual_functions() {
+        let (pressure, temperature, humidity_saturation, molar_co2) =
+            (101.325, 310.15, 100.0, 0.04);
+        let state = AirState::new(pressure, temperature, humidity_saturation, molar_co2);
+
+        assert_eq!(
+            calculate_air_density(pressure, temperature, humidity_saturation, molar_co2),
+            state.air_density
+        );
+        assert_eq!(
+            calculate_prandtl_number(pressure, temperature, humidity_saturation, molar_co2),
+            state.prandtl_number
+        );
+        assert_eq!(
+            calculate_sound_speed(pressure, temperature, humidity_saturation, molar_co2),
+            state.sound_speed
+        );
+        assert_eq!(
+            calculate_alpha_constant(pressure, temperature, humidity_saturation, molar_co2),
+            state.alpha_constant
+        );
+        assert_eq!(
+            calculate_molar_water_vapour(pressure, temperature, humidity_saturation),
+            state.molar_water_vapour
+        );
+    }
+
+    #[test]
+    fn it_keeps_the_reference_sound_speed() {
+        let state = AirState::new(101.325, 293.15, 0.0, 0.00039);
+        assert_eq!(343.2878525006776, state.sound_speed);
+        assert_eq!(
+            2.0 * std::f64::consts::PI / state.sound_speed,
+            state.wave_number
+        );
+    }
+}
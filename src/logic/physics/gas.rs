@@ -0,0 +1,343 @@
+use std::{error::Error, f64::consts::PI, fmt};
+
+use crate::structs::parameters::{
+    ParameterError, PhysicalParameters, PRESSURE_RANGE, TEMPERATURE_RANGE,
+};
+
+use super::{MOLAR_MASS_CO2, MOLAR_MASS_O2, UNIVERSAL_GAS_CONSTANT};
+
+// Temperature at which component viscosities and conductivities are given.
+pub const REFERENCE_TEMPERATURE: f64 = 273.15; // K
+
+// Tolerance on the sum of the molar fractions of a mixture.
+const FRACTION_SUM_TOLERANCE: f64 = 1e-6;
+
+// Mason & Saxena, 1958, correction of the unlike interactions in Wassiljewa's
+// equation for the thermal conductivity of a mixture.
+const MASON_SAXENA_FACTOR: f64 = 1.065;
+
+// Properties of one pure gas, treated as ideal.
+// Viscosity and conductivity follow Sutherland's law, with constants from White, 2006.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasComponent {
+    pub name: String,
+    pub molar_mass: f64,              // kg/kmol
+    pub specific_heat: f64,           // Isobaric specific heat J/(kg.K), taken as constant
+    pub dynamic_viscosity: f64,       // kg/(m.s) at the reference temperature
+    pub viscosity_sutherland: f64,    // Sutherland constant for viscosity, K
+    pub thermal_conductivity: f64,    // W/(m.K) at the reference temperature
+    pub conductivity_sutherland: f64, // Sutherland constant for conductivity, K
+}
+
+fn sutherland(reference_value: f64, sutherland_constant: f64, temperature: f64) -> f64 {
+    return reference_value
+        * (temperature / REFERENCE_TEMPERATURE).powf(1.5)
+        * (REFERENCE_TEMPERATURE + sutherland_constant)
+        / (temperature + sutherland_constant);
+}
+
+impl GasComponent {
+    pub fn nitrogen() -> Self {
+        return Self {
+            name: "N2".to_string(),
+            molar_mass: 28.0134,
+            specific_heat: 1040.0,
+            dynamic_viscosity: 1.663e-5,
+            viscosity_sutherland: 107.0,
+            thermal_conductivity: 0.0242,
+            conductivity_sutherland: 150.0,
+        };
+    }
+
+    pub fn oxygen() -> Self {
+        return Self {
+            name: "O2".to_string(),
+            molar_mass: MOLAR_MASS_O2,
+            specific_heat: 918.0,
+            dynamic_viscosity: 1.919e-5,
+            viscosity_sutherland: 139.0,
+            thermal_conductivity: 0.0244,
+            conductivity_sutherland: 240.0,
+        };
+    }
+
+    pub fn argon() -> Self {
+        return Self {
+            name: "Ar".to_string(),
+            molar_mass: 39.948,
+            specific_heat: 520.3,
+            dynamic_viscosity: 2.125e-5,
+            viscosity_sutherland: 114.0,
+            thermal_conductivity: 0.0163,
+            conductivity_sutherland: 170.0,
+        };
+    }
+
+    pub fn carbon_dioxide() -> Self {
+        return Self {
+            name: "CO2".to_string(),
+            molar_mass: MOLAR_MASS_CO2,
+            specific_heat: 846.0,
+            dynamic_viscosity: 1.370e-5,
+            viscosity_sutherland: 222.0,
+            thermal_conductivity: 0.0146,
+            conductivity_sutherland: 1800.0,
+        };
+    }
+
+    pub fn helium() -> Self {
+        return Self {
+            name: "He".to_string(),
+            molar_mass: 4.002602,
+            specific_heat: 5193.0,
+            dynamic_viscosity: 1.87e-5,
+            viscosity_sutherland: 79.4,
+            thermal_conductivity: 0.1422,
+            conductivity_sutherland: 79.4,
+        };
+    }
+
+    pub fn dynamic_viscosity_at(&self, temperature: f64) -> f64 {
+        return sutherland(
+            self.dynamic_viscosity,
+            self.viscosity_sutherland,
+            temperature,
+        );
+    }
+
+    pub fn thermal_conductivity_at(&self, temperature: f64) -> f64 {
+        return sutherland(
+            self.thermal_conductivity,
+            self.conductivity_sutherland,
+            temperature,
+        );
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GasMixtureError {
+    Empty,
+    InvalidFraction(usize), // Index of a negative or non-finite molar fraction
+    InvalidComponent(usize), // Index of a component with a non-positive or non-finite property
+    FractionsDoNotSumToOne(f64), // Sum of the molar fractions
+}
+
+impl fmt::Display for GasMixtureError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            GasMixtureError::Empty => write!(f, "a gas mixture needs at least one component"),
+            GasMixtureError::InvalidFraction(index) => {
+                write!(f, "component {} has an invalid molar fraction", index)
+            }
+            GasMixtureError::InvalidComponent(index) => {
+                write!(
+                    f,
+                    "component {} has a non-positive or non-finite property",
+                    index
+                )
+            }
+            GasMixtureError::FractionsDoNotSumToOne(sum) => {
+                write!(f, "molar fractions sum to {} instead of 1", sum)
+            }
+        };
+    }
+}
+
+impl Error for GasMixtureError {}
+
+// Ideal gas mixture given by the molar fraction of each component.
+#[derive(Debug, Clone, PartialEq)]
+pub struct GasMixture {
+    components: Vec<(GasComponent, f64)>,
+}
+
+impl GasMixture {
+    pub fn new(components: Vec<(GasComponent, f64)>) -> Result<Self, GasMixtureError> {
+        if components.is_empty() {
+            return Err(GasMixtureError::Empty);
+        }
+        for (index, (component, fraction)) in components.iter().enumerate() {
+            if !fraction.is_finite() || *fraction < 0.0 {
+                return Err(GasMixtureError::InvalidFraction(index));
+            }
+            let properties = [
+                component.molar_mass,
+                component.specific_heat,
+                component.dynamic_viscosity,
+                component.thermal_conductivity,
+                // Sutherland constants at or below -T would divide by zero or flip signs.
+                component.viscosity_sutherland,
+                component.conductivity_sutherland,
+            ];
+            if properties
+                .iter()
+                .any(|value| *value <= 0.0 || !value.is_finite())
+            {
+                return Err(GasMixtureError::InvalidComponent(index));
+            }
+        }
+        let sum: f64 = components.iter().map(|(_, fraction)| fraction).sum();
+        if (sum - 1.0).abs() > FRACTION_SUM_TOLERANCE {
+            return Err(GasMixtureError::FractionsDoNotSumToOne(sum));
+        }
+        return Ok(Self { components });
+    }
+
+    // Dry air with 390 ppm of CO2, as in the default ParametersBuilder.
+    pub fn dry_air() -> Self {
+        return Self {
+            components: vec![
+                (GasComponent::nitrogen(), 0.78081),
+                (GasComponent::oxygen(), 0.20946),
+                (GasComponent::argon(), 0.00934),
+                (GasComponent::carbon_dioxide(), 0.00039),
+            ],
+        };
+    }
+
+    pub fn nitrogen() -> Self {
+        return Self {
+            components: vec![(GasComponent::nitrogen(), 1.0)],
+        };
+    }
+
+    // Dry air diluted with a molar fraction of helium.
+    pub fn helium_enriched_air(helium_fraction: f64) -> Result<Self, GasMixtureError> {
+        let mut components: Vec<(GasComponent, f64)> = Self::dry_air()
+            .components
+            .into_iter()
+            .map(|(component, fraction)| (component, fraction * (1.0 - helium_fraction)))
+            .collect();
+        components.push((GasComponent::helium(), helium_fraction));
+        return Self::new(components);
+    }
+
+    pub fn components(&self) -> &[(GasComponent, f64)] {
+        return &self.components;
+    }
+
+    pub fn molar_fraction_of(&self, name: &str) -> f64 {
+        return self
+            .components
+            .iter()
+            .filter(|(component, _)| component.name == name)
+            .map(|(_, fraction)| fraction)
+            .sum();
+    }
+
+    // Molar mass in kg/kmol.
+    pub fn molar_mass(&self) -> f64 {
+        return self
+            .components
+            .iter()
+            .map(|(component, fraction)| fraction * component.molar_mass)
+            .sum();
+    }
+
+    // Isobaric specific heat in J/(kg.K), weighted by mass fraction.
+    pub fn specific_heat(&self) -> f64 {
+        let molar_mass = self.molar_mass();
+        return self
+            .components
+            .iter()
+            .map(|(component, fraction)| {
+                fraction * component.molar_mass / molar_mass * component.specific_heat
+            })
+            .sum();
+    }
+
+    // Wilke's interaction parameter phi_ij for viscosity mixing, also the base of the
+    // Wassiljewa coefficients A_ij for conductivity.
+    fn phi(&self, i: usize, j: usize, temperature: f64) -> f64 {
+        let (first, second) = (&self.components[i].0, &self.components[j].0);
+        let viscosity_ratio =
+            first.dynamic_viscosity_at(temperature) / second.dynamic_viscosity_at(temperature);
+        return (1.0 + viscosity_ratio.sqrt() * (second.molar_mass / first.molar_mass).powf(0.25))
+            .powi(2)
+            / (8.0 * (1.0 + first.molar_mass / second.molar_mass)).sqrt();
+    }
+
+    // Mixes a per-component property as sum(x_i.p_i / sum(x_j.A_ij)), where A_ii = 1 and
+    // A_ij = factor.phi_ij otherwise: Wilke's rule for a factor of 1, and Wassiljewa's
+    // equation with the Mason-Saxena coefficients for MASON_SAXENA_FACTOR.
+    fn mix(&self, temperature: f64, factor: f64, property: impl Fn(&GasComponent) -> f64) -> f64 {
+        let mut result = 0.0;
+        for (i, (component, fraction)) in self.components.iter().enumerate() {
+            if *fraction == 0.0 {
+                continue;
+            }
+            let denominator: f64 = (0..self.components.len())
+                .map(|j| {
+                    let coefficient = if i == j {
+                        1.0
+                    } else {
+                        factor * self.phi(i, j, temperature)
+                    };
+                    return self.components[j].1 * coefficient;
+                })
+                .sum();
+            result += fraction * property(component) / denominator;
+        }
+        return result;
+    }
+
+    pub fn dynamic_viscosity(&self, temperature: f64) -> f64 {
+        return self.mix(temperature, 1.0, |component| {
+            component.dynamic_viscosity_at(temperature)
+        });
+    }
+
+    pub fn thermal_conductivity(&self, temperature: f64) -> f64 {
+        return self.mix(temperature, MASON_SAXENA_FACTOR, |component| {
+            component.thermal_conductivity_at(temperature)
+        });
+    }
+
+    // Physical parameters of the mixture at a pressure in kPa and a temperature in Kelvin,
+    // within the same ranges as ParametersBuilder::try_build.
+    // Humidity and water vapour are zero; include water as a component if needed.
+    pub fn physical_parameters(
+        &self,
+        pressure: f64,
+        temperature: f64,
+    ) -> Result<PhysicalParameters, ParameterError> {
+        if !TEMPERATURE_RANGE.contains(&temperature) {
+            return Err(ParameterError::TemperatureOutOfRange(temperature));
+        }
+        if !PRESSURE_RANGE.contains(&pressure) {
+            return Err(ParameterError::PressureOutOfRange(pressure));
+        }
+        let gas_constant = UNIVERSAL_GAS_CONSTANT / (0.001 * self.molar_mass());
+        let air_density = pressure * 1e3 / (gas_constant * temperature);
+        let dynamic_viscosity = self.dynamic_viscosity(temperature);
+        let specific_heat = self.specific_heat();
+        let specific_heats_ratio = specific_heat / (specific_heat - gas_constant);
+        let thermal_conductivity = self.thermal_conductivity(temperature);
+        let prandtl_number = dynamic_viscosity * specific_heat / thermal_conductivity;
+        let sound_speed = (specific_heats_ratio * gas_constant * temperature).sqrt();
+        let loss_factor = 1.0 + (specific_heats_ratio - 1.0) / prandtl_number.sqrt();
+        return Ok(PhysicalParameters {
+            temperature,
+            pressure,
+            molar_co2: self.molar_fraction_of("CO2"),
+            molar_water_vapour: 0.0,
+            humidity_saturation: 0.0,
+            air_density,
+            dynamic_viscosity,
+            specific_heat,
+            specific_heats_ratio,
+            thermal_conductivity,
+            prandtl_number,
+            sound_speed,
+            epsilon_constant: 1.0 / (2.0 * PI.sqrt())
+                * (dynamic_viscosity / air_density).sqrt()
+                * loss_factor,
+            alpha_constant: (dynamic_viscosity / (2.0 * air_density * sound_speed)).sqrt()
+                * loss_factor,
+            wave_number: 2.0 * PI / sound_speed,
+        });
+    }
+}
+
+#[cfg(test)]
+mod gas_tests;
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod gas_tests {
+    use crate::{
+        logic::physics::temperature::TemperatureType,
+        structs::parameters::{ParameterError, ParametersBuilder},
+    };
+
+    use super::super::*;
+
+    #[test]
+    fn it_agrees_with_the_moist_air_model_for_dry_air() {
+        let expected = ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .with_humidity_saturation(0.0)
+            .build();
+        let actual = GasMixture::dry_air()
+            .physical_parameters(101.325, 293.15)
+            .unwrap();
+
+        assert!((actual.sound_speed / expected.sound_speed - 1.0).abs() < 1e-3);
+        assert!((actual.air_density / expected.air_density - 1.0).abs() < 1e-3);
+        assert!((actual.dynamic_viscosity / expected.dynamic_viscosity - 1.0).abs() < 0.01);
+        assert!((actual.prandtl_number / expected.prandtl_number - 1.0).abs() < 0.02);
+        assert_eq!(0.00039, actual.molar_co2);
+    }
+
+    #[test]
+    fn it_models_helium() {
+        let helium = GasMixture::new(vec![(GasComponent::helium(), 1.0)]).unwrap();
+        let actual = helium.physical_parameters(101.325, 293.15).unwrap();
+        assert!((actual.specific_heats_ratio - 5.0 / 3.0).abs() < 1e-3);
+        assert!((actual.sound_speed - 1007.0).abs() < 2.0);
+
+        let air = GasMixture::dry_air()
+            .physical_parameters(101.325, 293.15)
+            .unwrap();
+        let enriched = GasMixture::helium_enriched_air(0.5)
+            .unwrap()
+            .physical_parameters(101.325, 293.15)
+            .unwrap();
+        assert!(enriched.sound_speed > air.sound_speed);
+        assert!(enriched.sound_speed < actual.sound_speed);
+    }
+
+    #[test]
+    fn it_models_nitrogen_fills() {
+        let actual = GasMixture::nitrogen()
+            .physical_parameters(101.325, 293.15)
+            .unwrap();
+        assert!((actual.sound_speed - 349.0).abs() < 1.0);
+        assert_eq!(0.0, actual.molar_co2);
+    }
+
+    #[test]
+    fn it_mixes_conductivity_with_mason_saxena_coefficients() {
+        let (nitrogen, helium) = (GasComponent::nitrogen(), GasComponent::helium());
+        let mixture =
+            GasMixture::new(vec![(nitrogen.clone(), 0.5), (helium.clone(), 0.5)]).unwrap();
+        let phi = |first: &GasComponent, second: &GasComponent| {
+            let ratio = first.dynamic_viscosity_at(300.0) / second.dynamic_viscosity_at(300.0);
+            return (1.0 + ratio.sqrt() * (second.molar_mass / first.molar_mass).powf(0.25))
+                .powi(2)
+                / (8.0 * (1.0 + first.molar_mass / second.molar_mass)).sqrt();
+        };
+        let expected = 0.5 * nitrogen.thermal_conductivity_at(300.0)
+            / (0.5 + 0.5 * 1.065 * phi(&nitrogen, &helium))
+            + 0.5 * helium.thermal_conductivity_at(300.0)
+                / (0.5 + 0.5 * 1.065 * phi(&helium, &nitrogen));
+        assert!((mixture.thermal_conductivity(300.0) / expected - 1.0).abs() < 1e-12);
+
+        let expected = 0.5 * nitrogen.dynamic_viscosity_at(300.0)
+            / (0.5 + 0.5 * phi(&nitrogen, &helium))
+            + 0.5 * helium.dynamic_viscosity_at(300.0) / (0.5 + 0.5 * phi(&helium, &nitrogen));
+        assert!((mixture.dynamic_viscosity(300.0) / expected - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_validates_the_state() {
+        let air = GasMixture::dry_air();
+        assert_eq!(
+            Some(ParameterError::PressureOutOfRange(-1.0)),
+            air.physical_parameters(-1.0, 293.15).err()
+        );
+        assert_eq!(
+            Some(ParameterError::TemperatureOutOfRange(0.0)),
+            air.physical_parameters(101.325, 0.0).err()
+        );
+        assert!(air.physical_parameters(101.325, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn it_rejects_invalid_mixtures() {
+        assert_eq!(Some(GasMixtureError::Empty), GasMixture::new(vec![]).err());
+        assert_eq!(
+            Some(GasMixtureError::FractionsDoNotSumToOne(0.5)),
+            GasMixture::new(vec![(GasComponent::nitrogen(), 0.5)]).err()
+        );
+        assert_eq!(
+            Some(GasMixtureError::InvalidFraction(4)),
+            GasMixture::helium_enriched_air(-0.1).err()
+        );
+        let mut component = GasComponent::nitrogen();
+        component.viscosity_sutherland = -293.15;
+        assert_eq!(
+            Some(GasMixtureError::InvalidComponent(0)),
+            GasMixture::new(vec![(component, 1.0)]).err()
+        );
+        let mut component = GasComponent::nitrogen();
+        component.conductivity_sutherland = f64::NAN;
+        assert_eq!(
+            Some(GasMixtureError::InvalidComponent(0)),
+            GasMixture::new(vec![(component, 1.0)]).err()
+        );
+    }
+}
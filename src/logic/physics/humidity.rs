@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+
+use super::{
+    calcs::{calculate_enhancement_factor, temperature_to_vapour_pressure},
+    MOLAR_MASS_WATER_VAPOUR, UNIVERSAL_GAS_CONSTANT,
+};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HumidityType {
+    #[default]
+    Saturation, // % of saturation (relative humidity)
+    DewPoint,         // Dew point temperature in Kelvin degrees
+    MolarWaterVapour, // Molar fraction of water vapour in mol/mol
+    Absolute,         // Mass of water vapour per volume of air in g/m^3
+}
+
+// Partial pressure of water vapour in kPa, as in CIPM-2007 with f.p_sv.
+fn vapour_pressure(pressure: f64, temperature: f64) -> f64 {
+    return calculate_enhancement_factor(pressure, temperature)
+        * temperature_to_vapour_pressure(temperature);
+}
+
+pub fn dew_point_to_humidity_saturation(pressure: f64, temperature: f64, dew_point: f64) -> f64 {
+    return 100.0 * vapour_pressure(pressure, dew_point) / vapour_pressure(pressure, temperature);
+}
+
+pub fn molar_water_vapour_to_humidity_saturation(
+    pressure: f64,
+    temperature: f64,
+    molar_water_vapour: f64,
+) -> f64 {
+    return 100.0 * molar_water_vapour * pressure / vapour_pressure(pressure, temperature);
+}
+
+pub fn absolute_humidity_to_humidity_saturation(
+    pressure: f64,
+    temperature: f64,
+    absolute_humidity: f64,
+) -> f64 {
+    // Water vapour treated as an ideal gas; compressibility is within 0.1 % of unity here.
+    let molar_water_vapour = absolute_humidity * UNIVERSAL_GAS_CONSTANT * temperature
+        / (MOLAR_MASS_WATER_VAPOUR * pressure * 1e3);
+    return molar_water_vapour_to_humidity_saturation(pressure, temperature, molar_water_vapour);
+}
+
+pub(crate) fn normalize_humidity(
+    humidity: f64,
+    humidity_type: HumidityType,
+    pressure: f64,
+    temperature: f64,
+) -> f64 {
+    return match humidity_type {
+        HumidityType::Saturation => humidity,
+        HumidityType::DewPoint => dew_point_to_humidity_saturation(pressure, temperature, humidity),
+        HumidityType::MolarWaterVapour => {
+            molar_water_vapour_to_humidity_saturation(pressure, temperature, humidity)
+        }
+        HumidityType::Absolute => {
+            absolute_humidity_to_humidity_saturation(pressure, temperature, humidity)
+        }
+    };
+}
+
+#[cfg(test)]
+mod humidity_tests;
@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod humidity_tests {
+    use crate::logic::physics::calcs::calculate_molar_water_vapour;
+
+    use super::super::*;
+
+    #[test]
+    fn it_is_saturated_at_the_dew_point() {
+        let actual = dew_point_to_humidity_saturation(101.325, 293.15, 293.15);
+        assert!((actual - 100.0).abs() < 1e-12);
+
+        // A 10 C dew point in a 20 C room is about 52 % relative humidity.
+        let actual = dew_point_to_humidity_saturation(101.325, 293.15, 283.15);
+        assert!((actual - 52.5).abs() < 0.5);
+    }
+
+    #[test]
+    fn it_inverts_the_molar_water_vapour() {
+        let molar_water_vapour = calculate_molar_water_vapour(101.325, 293.15, 45.0);
+        let actual = molar_water_vapour_to_humidity_saturation(101.325, 293.15, molar_water_vapour);
+        assert!((actual - 45.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_can_use_absolute_humidity() {
+        // Saturated air at 20 C holds about 17.3 g/m^3 of water, a 2.31 % molar fraction.
+        let humidity_saturation = absolute_humidity_to_humidity_saturation(101.325, 293.15, 17.3);
+        let actual = calculate_molar_water_vapour(101.325, 293.15, humidity_saturation);
+        assert!((actual - 0.0231).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,170 @@
+use std::f64::consts::PI;
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+
+use crate::structs::parameters::PhysicalParameters;
+
+use super::bessel::bessel_j1_over_j0;
+
+// Viscothermal boundary-layer losses of a cylindrical bore of nominal radius.
+// Propagation follows exp(-Gamma.x), so the real part of Gamma is the attenuation.
+pub trait ViscothermalLossModel {
+    fn name(&self) -> &'static str;
+    // Complex propagation constant Gamma, in 1/m
+    fn propagation_constant(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64;
+    // Complex characteristic impedance Zc, in kg/(m^4.s)
+    fn characteristic_impedance(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64;
+}
+
+// No losses, Gamma = j.k and Zc = rho.c/S.
+pub struct Lossless;
+
+impl ViscothermalLossModel for Lossless {
+    fn name(&self) -> &'static str {
+        return "Lossless";
+    }
+
+    fn propagation_constant(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        _radius: f64,
+    ) -> Complex64 {
+        return Complex64::new(0.0, parameters.wave_number(frequency));
+    }
+
+    fn characteristic_impedance(
+        &self,
+        parameters: &PhysicalParameters,
+        _frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        return Complex64::new(parameters.wave_impedance(radius), 0.0);
+    }
+}
+
+// First-order large-radius approximation from Keefe, 1984, valid while the
+// boundary layers are thin compared with the radius (shear number well above 10).
+pub struct Keefe1984;
+
+impl ViscothermalLossModel for Keefe1984 {
+    fn name(&self) -> &'static str {
+        return "Keefe 1984";
+    }
+
+    fn propagation_constant(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        return parameters.propagation_constant(frequency, radius);
+    }
+
+    fn characteristic_impedance(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        return parameters.characteristic_impedance(frequency, radius);
+    }
+}
+
+// Full Zwikker-Kosten model with Bessel functions, valid down to capillary radii.
+// At 0 Hz the boundary-layer functions are 0/0; the lossless limit Gamma = 0 and
+// Zc = rho.c/S is returned instead, as for Keefe1984.
+pub struct ZwikkerKosten;
+
+impl ZwikkerKosten {
+    // Viscous and thermal boundary-layer functions (1 - Fv, 1 + (gamma - 1).Ft).
+    fn boundary_layer_factors(
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> (Complex64, Complex64) {
+        let shear_number = shear_number(parameters, frequency, radius);
+        let thermal_number = shear_number * parameters.prandtl_number.sqrt();
+        let boundary_function = |number: f64| {
+            // F(x) = 2.J1(z)/(z.J0(z)), with z = x.sqrt(-j).
+            let z = number * Complex64::new(0.0, -1.0).sqrt();
+            return 2.0 * bessel_j1_over_j0(z) / z;
+        };
+        return (
+            1.0 - boundary_function(shear_number),
+            1.0 + (parameters.specific_heats_ratio - 1.0) * boundary_function(thermal_number),
+        );
+    }
+}
+
+impl ViscothermalLossModel for ZwikkerKosten {
+    fn name(&self) -> &'static str {
+        return "Zwikker-Kosten";
+    }
+
+    fn propagation_constant(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        if frequency == 0.0 {
+            return Complex64::default();
+        }
+        let (viscous, thermal) = Self::boundary_layer_factors(parameters, frequency, radius);
+        return Complex64::new(0.0, parameters.wave_number(frequency)) * (thermal / viscous).sqrt();
+    }
+
+    fn characteristic_impedance(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        if frequency == 0.0 {
+            return Complex64::new(parameters.wave_impedance(radius), 0.0);
+        }
+        let (viscous, thermal) = Self::boundary_layer_factors(parameters, frequency, radius);
+        return parameters.wave_impedance(radius) / (viscous * thermal).sqrt();
+    }
+}
+
+// Ratio of the radius to the viscous boundary-layer thickness, r.sqrt(rho.omega/mu).
+pub fn shear_number(parameters: &PhysicalParameters, frequency: f64, radius: f64) -> f64 {
+    return radius
+        * (parameters.air_density * 2.0 * PI * frequency / parameters.dynamic_viscosity).sqrt();
+}
+
+// Selection of a built-in loss model, for storing with a calculation.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LossModel {
+    Lossless,
+    #[default]
+    Keefe1984,
+    ZwikkerKosten,
+}
+
+impl LossModel {
+    pub fn model(self) -> &'static dyn ViscothermalLossModel {
+        return match self {
+            LossModel::Lossless => &Lossless,
+            LossModel::Keefe1984 => &Keefe1984,
+            LossModel::ZwikkerKosten => &ZwikkerKosten,
+        };
+    }
+}
+
+#[cfg(test)]
+mod losses_tests;
@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod losses_tests {
+    use num_complex::Complex64;
+
+    use crate::{
+        logic::physics::wall::{BoreMaterial, WallLoss},
+        structs::parameters::{ParametersBuilder, PhysicalParameters},
+    };
+
+    use super::super::*;
+
+    fn parameters() -> PhysicalParameters {
+        return ParametersBuilder::new().build();
+    }
+
+    #[test]
+    fn it_keeps_the_current_losses_by_default() {
+        let parameters = parameters();
+        let model = LossModel::default().model();
+        assert_eq!("Keefe 1984", model.name());
+        assert_eq!(
+            parameters.propagation_constant(440.0, 0.008),
+            model.propagation_constant(&parameters, 440.0, 0.008)
+        );
+        assert_eq!(
+            parameters.characteristic_impedance(440.0, 0.008),
+            model.characteristic_impedance(&parameters, 440.0, 0.008)
+        );
+    }
+
+    #[test]
+    fn it_has_no_attenuation_without_losses() {
+        let parameters = parameters();
+        let gamma = Lossless.propagation_constant(&parameters, 440.0, 0.008);
+        assert_eq!(Complex64::new(0.0, parameters.wave_number(440.0)), gamma);
+        let impedance = Lossless.characteristic_impedance(&parameters, 440.0, 0.008);
+        assert_eq!(
+            Complex64::new(parameters.wave_impedance(0.008), 0.0),
+            impedance
+        );
+    }
+
+    #[test]
+    fn it_matches_keefe_for_wide_bores() {
+        let parameters = parameters();
+        for frequency in [100.0, 440.0, 2000.0] {
+            let keefe = Keefe1984.propagation_constant(&parameters, frequency, 0.01);
+            let exact = ZwikkerKosten.propagation_constant(&parameters, frequency, 0.01);
+            assert!(((exact.re - keefe.re) / keefe.re).abs() < 0.02);
+            assert!(((exact.im - keefe.im) / keefe.im).abs() < 1e-4);
+
+            let keefe = Keefe1984.characteristic_impedance(&parameters, frequency, 0.01);
+            let exact = ZwikkerKosten.characteristic_impedance(&parameters, frequency, 0.01);
+            assert!((exact - keefe).norm() / keefe.norm() < 0.02);
+        }
+    }
+
+    #[test]
+    fn it_adds_losses_in_narrow_bores() {
+        let parameters = parameters();
+        assert!(shear_number(&parameters, 440.0, 0.0005) < 10.0);
+        let keefe = Keefe1984.propagation_constant(&parameters, 440.0, 0.0005);
+        let exact = ZwikkerKosten.propagation_constant(&parameters, 440.0, 0.0005);
+        assert!(exact.re > 1.1 * keefe.re);
+        assert!(exact.im < keefe.im);
+    }
+
+    #[test]
+    fn it_tends_to_poiseuille_flow_in_capillaries() {
+        // At low shear numbers the viscous resistance per unit length is 8.mu/(pi.r^4).
+        let parameters = parameters();
+        let (frequency, radius) = (1.0, 0.0001);
+        let gamma = ZwikkerKosten.propagation_constant(&parameters, frequency, radius);
+        let impedance = ZwikkerKosten.characteristic_impedance(&parameters, frequency, radius);
+        let series = gamma * impedance;
+        let expected = 8.0 * parameters.dynamic_viscosity / (std::f64::consts::PI * radius.powi(4));
+        assert!((series.re - expected).abs() / expected < 1e-3);
+    }
+
+    #[test]
+    fn it_is_finite_at_zero_frequency() {
+        let parameters = parameters();
+        let rough = WallLoss::from_material(LossModel::ZwikkerKosten, BoreMaterial::Pla);
+        let models: [&dyn ViscothermalLossModel; 4] =
+            [&Lossless, &Keefe1984, &ZwikkerKosten, &rough];
+        for model in models {
+            let gamma = model.propagation_constant(&parameters, 0.0, 0.008);
+            assert_eq!(Complex64::default(), gamma, "{}", model.name());
+            let impedance = model.characteristic_impedance(&parameters, 0.0, 0.008);
+            assert_eq!(
+                Complex64::new(parameters.wave_impedance(0.008), 0.0),
+                impedance,
+                "{}",
+                model.name()
+            );
+        }
+    }
+
+    #[test]
+    fn it_serializes_the_model_choice() {
+        let json = serde_json::to_string(&LossModel::ZwikkerKosten).unwrap();
+        assert_eq!("\"zwikker_kosten\"", json);
+        let model: LossModel = serde_json::from_str(&json).unwrap();
+        assert_eq!("Zwikker-Kosten", model.model().name());
+    }
+}
@@ -1,6 +1,18 @@
+pub mod atmosphere;
+pub mod batch;
+pub mod bessel;
 pub mod calcs;
+pub mod gas;
+pub mod humidity;
+pub mod losses;
+pub mod models;
 pub mod parameters;
+pub mod pressure;
+pub mod report;
+pub mod sensitivity;
+pub mod table;
 pub mod temperature;
+pub mod wall;
 
 pub const UNIVERSAL_GAS_CONSTANT: f64 = 8.314472; // J/mol K.
 pub const MOLAR_MASS_WATER_VAPOUR: f64 = 18.01527; // kg/kMol
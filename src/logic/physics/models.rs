@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+use super::{calcs::AirState, temperature::kelvin_to_celsius};
+
+// Alternative formulas for the speed of sound in air.
+// The other air properties always come from the CIPM-2007 and Tsilingiris fits.
+pub trait AirPropertyModel {
+    fn name(&self) -> &'static str;
+    fn sound_speed(&self, state: &AirState) -> f64;
+}
+
+// Ideal gas with CIPM-2007 compressibility and Tsilingiris specific heats.
+pub struct Cipm2007;
+
+impl AirPropertyModel for Cipm2007 {
+    fn name(&self) -> &'static str {
+        return "CIPM-2007";
+    }
+
+    fn sound_speed(&self, state: &AirState) -> f64 {
+        return state.sound_speed;
+    }
+}
+
+// Empirical fit from Cramer, 1993, for 0-30 C, 60-110 kPa,
+// up to 6 % water vapour and 1 % CO2 by molar fraction.
+pub struct Cramer1993;
+
+const CRAMER_COEFFICIENTS: [f64; 16] = [
+    331.5024, 0.603055, -0.000528, 51.471935, 0.1495874, -0.000782, -1.82e-7, 3.73e-8, -2.93e-10,
+    -85.20931, -0.228525, 5.91e-5, -2.835149, -2.15e-13, 29.179762, 0.000486,
+];
+
+impl AirPropertyModel for Cramer1993 {
+    fn name(&self) -> &'static str {
+        return "Cramer 1993";
+    }
+
+    fn sound_speed(&self, state: &AirState) -> f64 {
+        let a = CRAMER_COEFFICIENTS;
+        let t = kelvin_to_celsius(state.temperature);
+        let p = state.pressure * 1e3;
+        let xw = state.molar_water_vapour;
+        let xc = state.molar_co2;
+        return a[0]
+            + a[1] * t
+            + a[2] * t * t
+            + (a[3] + a[4] * t + a[5] * t * t) * xw
+            + (a[6] + a[7] * t + a[8] * t * t) * p
+            + (a[9] + a[10] * t + a[11] * t * t) * xc
+            + a[12] * xw * xw
+            + a[13] * p * p
+            + a[14] * xc * xc
+            + a[15] * xw * p * xc;
+    }
+}
+
+// Textbook dry-air formula, 331.3 m/s at 0 C scaled with the square root of temperature.
+pub struct IdealDryAir;
+
+impl AirPropertyModel for IdealDryAir {
+    fn name(&self) -> &'static str {
+        return "Ideal dry air";
+    }
+
+    fn sound_speed(&self, state: &AirState) -> f64 {
+        return 331.3 * (state.temperature / 273.15).sqrt();
+    }
+}
+
+// Selection of a built-in model, as stored by ParametersBuilder.
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AirModel {
+    #[default]
+    Cipm2007,
+    Cramer1993,
+    IdealDryAir,
+}
+
+impl AirModel {
+    pub fn model(self) -> &'static dyn AirPropertyModel {
+        return match self {
+            AirModel::Cipm2007 => &Cipm2007,
+            AirModel::Cramer1993 => &Cramer1993,
+            AirModel::IdealDryAir => &IdealDryAir,
+        };
+    }
+}
+
+#[cfg(test)]
+mod models_tests;
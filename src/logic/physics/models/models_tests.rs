@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod models_tests {
+    use crate::{
+        logic::physics::{calcs::AirState, temperature::TemperatureType},
+        structs::parameters::ParametersBuilder,
+    };
+
+    use super::super::*;
+
+    #[test]
+    fn it_keeps_the_reference_values_by_default() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(37.0, TemperatureType::C)
+            .with_humidity_saturation(100.0)
+            .with_molar_co2(0.04)
+            .with_model(AirModel::Cipm2007)
+            .build();
+        assert_eq!(363.99110139436897, parameters.sound_speed);
+    }
+
+    #[test]
+    fn it_can_use_published_formulas() {
+        let state = AirState::new(101.325, 273.15, 0.0, 0.000314);
+        assert!((Cramer1993.sound_speed(&state) - 331.45).abs() < 0.01);
+        assert_eq!(331.3, IdealDryAir.sound_speed(&state));
+
+        let state = AirState::new(101.325, 293.15, 0.0, 0.00039);
+        let cipm = Cipm2007.sound_speed(&state);
+        assert!((Cramer1993.sound_speed(&state) - cipm).abs() < 0.1);
+        assert!((IdealDryAir.sound_speed(&state) - cipm).abs() < 0.2);
+    }
+
+    #[test]
+    fn it_derives_constants_from_the_selected_model() {
+        let parameters = ParametersBuilder::new()
+            .with_model(AirModel::IdealDryAir)
+            .build();
+        let expected = 331.3 * (parameters.temperature / 273.15).sqrt();
+        assert_eq!(expected, parameters.sound_speed);
+        assert_eq!(
+            2.0 * std::f64::consts::PI / expected,
+            parameters.wave_number
+        );
+
+        let parameters = ParametersBuilder::new().build_with(&Cramer1993);
+        let reference = ParametersBuilder::new().build();
+        assert_ne!(reference.sound_speed, parameters.sound_speed);
+        assert_eq!(reference.air_density, parameters.air_density);
+    }
+}
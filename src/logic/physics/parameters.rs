@@ -1,22 +1,112 @@
-use std::f64::consts::PI;
+use std::{error::Error, f64::consts::PI, fmt};
+
+use num_complex::Complex64;
 
 use crate::structs::parameters::PhysicalParameters;
 
-// Wave impedance of a bore of nominal radius, in kg/(m^4.s)
-pub fn wave_impedance(parameters: PhysicalParameters, radius: f64) -> f64 {
-    return parameters.air_density * parameters.sound_speed / (PI * radius * radius);
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FrequencyError {
+    InvalidFrequency(f64), // Negative or non-finite frequency in Hz
 }
 
-pub fn get_epsilon_from_f(parameters: PhysicalParameters, frequency: f64, radius: f64) -> f64 {
-    return parameters.epsilon_constant / (radius * frequency.sqrt());
+impl fmt::Display for FrequencyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            FrequencyError::InvalidFrequency(value) => {
+                write!(f, "frequency {} Hz must be finite and non-negative", value)
+            }
+        };
+    }
 }
 
-pub fn frequency(parameters: PhysicalParameters, wave_number: f64) -> f64 {
-    return wave_number / parameters.wave_number;
+impl Error for FrequencyError {}
+
+fn check_frequency(frequency: f64) -> Result<f64, FrequencyError> {
+    if !frequency.is_finite() || frequency < 0.0 {
+        return Err(FrequencyError::InvalidFrequency(frequency));
+    }
+    return Ok(frequency);
 }
 
-pub fn wave_number(parameters: PhysicalParameters, frequency: f64) -> f64 {
-    return frequency * parameters.wave_number;
+impl PhysicalParameters {
+    // Wave impedance of a bore of nominal radius, in kg/(m^4.s)
+    pub fn wave_impedance(&self, radius: f64) -> f64 {
+        return self.air_density * self.sound_speed / (PI * radius * radius);
+    }
+
+    // Radius of the bore with a given wave impedance in kg/(m^4.s)
+    pub fn radius_from_wave_impedance(&self, wave_impedance: f64) -> f64 {
+        return (self.air_density * self.sound_speed / (PI * wave_impedance)).sqrt();
+    }
+
+    pub fn get_epsilon_from_f(&self, frequency: f64, radius: f64) -> f64 {
+        return self.epsilon_constant / (radius * frequency.sqrt());
+    }
+
+    // Same loss factor as get_epsilon_from_f, expressed through the lossless wave number.
+    pub fn get_epsilon_from_k(&self, wave_number: f64, radius: f64) -> f64 {
+        return self.alpha_constant / (radius * wave_number.sqrt());
+    }
+
+    // Frequency at which a bore of nominal radius has the loss factor epsilon.
+    pub fn frequency_from_epsilon(&self, epsilon: f64, radius: f64) -> f64 {
+        return (self.epsilon_constant / (radius * epsilon)).powi(2);
+    }
+
+    // Radius of the bore with the loss factor epsilon at a frequency.
+    pub fn radius_from_epsilon(&self, epsilon: f64, frequency: f64) -> f64 {
+        return self.epsilon_constant / (epsilon * frequency.sqrt());
+    }
+
+    pub fn frequency(&self, wave_number: f64) -> f64 {
+        return wave_number / self.wave_number;
+    }
+
+    pub fn wave_number(&self, frequency: f64) -> f64 {
+        return frequency * self.wave_number;
+    }
+
+    // Complex propagation constant, Gamma = alpha + j*k*(1 + epsilon), in 1/m.
+    // Viscothermal losses follow Keefe's large-radius approximation, with alpha = k * epsilon.
+    // The frequency must be finite and non-negative, see try_propagation_constant; at 0 Hz
+    // the lossless limit Gamma = 0 is returned instead of the 0/0 of the approximation.
+    pub fn propagation_constant(&self, frequency: f64, radius: f64) -> Complex64 {
+        if frequency == 0.0 {
+            return Complex64::default();
+        }
+        let wave_number = self.wave_number(frequency);
+        let epsilon = self.get_epsilon_from_k(wave_number, radius);
+        return Complex64::new(wave_number * epsilon, wave_number * (1.0 + epsilon));
+    }
+
+    // Same as propagation_constant, rejecting negative and non-finite frequencies.
+    pub fn try_propagation_constant(
+        &self,
+        frequency: f64,
+        radius: f64,
+    ) -> Result<Complex64, FrequencyError> {
+        return Ok(self.propagation_constant(check_frequency(frequency)?, radius));
+    }
+
+    // Complex characteristic impedance of a lossy bore of nominal radius, in kg/(m^4.s).
+    // Same precondition as propagation_constant; at 0 Hz this is the wave impedance.
+    pub fn characteristic_impedance(&self, frequency: f64, radius: f64) -> Complex64 {
+        let impedance = self.wave_impedance(radius);
+        if frequency == 0.0 {
+            return Complex64::new(impedance, 0.0);
+        }
+        let epsilon = self.get_epsilon_from_k(self.wave_number(frequency), radius);
+        return Complex64::new(impedance * (1.0 + epsilon), -impedance * epsilon);
+    }
+
+    // Same as characteristic_impedance, rejecting negative and non-finite frequencies.
+    pub fn try_characteristic_impedance(
+        &self,
+        frequency: f64,
+        radius: f64,
+    ) -> Result<Complex64, FrequencyError> {
+        return Ok(self.characteristic_impedance(check_frequency(frequency)?, radius));
+    }
 }
 
 #[cfg(test)]
@@ -1,11 +1,20 @@
 #[cfg(test)]
 
 mod parameters_tests {
+    use num_complex::Complex64;
+
     use crate::{
-        logic::physics::temperature::TemperatureType, structs::parameters::ParametersBuilder,
+        logic::physics::{
+            atmosphere::{isothermal_pressure_at, pressure_at, STANDARD_PRESSURE},
+            models::AirModel,
+            parameters::FrequencyError,
+            pressure::PressureType,
+            temperature::TemperatureType,
+        },
+        structs::parameters::{ParameterError, ParametersBuilder, PhysicalParameters},
     };
 
-    use super::super::*;
+    use std::{sync::Arc, thread};
 
     #[test]
     fn it_can_build_from_temperature() {
@@ -20,7 +29,7 @@ mod parameters_tests {
         let parameters = ParametersBuilder::new()
             .with_temperature(20.0, TemperatureType::C)
             .build();
-        let actual = wave_impedance(parameters, 0.006);
+        let actual = parameters.wave_impedance(0.006);
         assert_eq!(3681860.1456449446, actual);
     }
 
@@ -37,7 +46,7 @@ mod parameters_tests {
         assert_eq!(1.2931565359886497, parameters.air_density);
         assert_eq!(
             1.5171852624336475,
-            get_epsilon_from_f(parameters, 1.0, 0.001)
+            parameters.get_epsilon_from_f(1.0, 0.001)
         );
     }
     #[test]
@@ -52,7 +61,7 @@ mod parameters_tests {
         assert_eq!(1.2051536496637991, parameters.air_density);
         assert_eq!(
             1.6161041209381368,
-            get_epsilon_from_f(parameters, 1.0, 0.001)
+            parameters.get_epsilon_from_f(1.0, 0.001)
         );
     }
 
@@ -69,7 +78,7 @@ mod parameters_tests {
         assert_eq!(1.2050288905087325, parameters.air_density);
         assert_eq!(
             1.6208019965566673,
-            get_epsilon_from_f(parameters, 1.0, 0.001)
+            parameters.get_epsilon_from_f(1.0, 0.001)
         );
     }
 
@@ -84,16 +93,13 @@ mod parameters_tests {
             .build();
         assert_eq!(363.99110139436897, parameters.sound_speed);
         assert_eq!(1.1571049683032653, parameters.air_density);
-        assert_eq!(
-            1.695452850871162,
-            get_epsilon_from_f(parameters, 1.0, 0.001)
-        );
+        assert_eq!(1.695452850871162, parameters.get_epsilon_from_f(1.0, 0.001));
     }
 
     #[test]
     fn it_works_at_1_km() {
-        // At 1 km.
-        // assert_eq!(89.996, PhysicalParameters.pressureAt(1000), 0.001);
+        // At 1 km, with the isothermal pressure model of the Java original.
+        assert!((isothermal_pressure_at(1000.0, STANDARD_PRESSURE) - 89.996).abs() < 0.001);
         let parameters = ParametersBuilder::new()
             .with_temperature(20.0, TemperatureType::C)
             .with_pressure(89.996)
@@ -104,7 +110,246 @@ mod parameters_tests {
         assert_eq!(1.0701809286452089, parameters.air_density);
         assert_eq!(
             1.7205031950036578,
-            get_epsilon_from_f(parameters, 1.0, 0.001)
+            parameters.get_epsilon_from_f(1.0, 0.001)
         );
     }
+
+    #[test]
+    fn it_can_calculate_propagation_constant() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .build();
+        let lossless = 440.0 * parameters.wave_number;
+        let epsilon = parameters.epsilon_constant / (0.008 * 440.0_f64.sqrt());
+        let actual = parameters.propagation_constant(440.0, 0.008);
+        assert!((actual.re - lossless * epsilon).abs() < 1e-12);
+        assert!((actual.im - lossless * (1.0 + epsilon)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_tends_to_lossless_in_wide_bores() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .build();
+        let lossless = 440.0 * parameters.wave_number;
+        let actual = parameters.propagation_constant(440.0, 1e6);
+        assert!(actual.re / lossless < 1e-9);
+        assert!((actual.im / lossless - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_can_calculate_characteristic_impedance() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .build();
+        let actual = parameters.characteristic_impedance(440.0, 0.006);
+        let epsilon = parameters.get_epsilon_from_f(440.0, 0.006);
+        let lossless = parameters.wave_impedance(0.006);
+        assert!((actual.re / lossless - (1.0 + epsilon)).abs() < 1e-12);
+        assert!((actual.im / lossless + epsilon).abs() < 1e-12);
+    }
+
+    #[test]
+    fn it_returns_the_lossless_limit_at_zero_frequency() {
+        let parameters = ParametersBuilder::new().build();
+        assert_eq!(
+            Complex64::default(),
+            parameters.propagation_constant(0.0, 0.008)
+        );
+        assert_eq!(
+            Complex64::new(parameters.wave_impedance(0.008), 0.0),
+            parameters.characteristic_impedance(0.0, 0.008)
+        );
+        assert_eq!(
+            Ok(Complex64::default()),
+            parameters.try_propagation_constant(0.0, 0.008)
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_frequencies() {
+        let parameters = ParametersBuilder::new().build();
+        assert_eq!(
+            Err(FrequencyError::InvalidFrequency(-440.0)),
+            parameters.try_propagation_constant(-440.0, 0.008)
+        );
+        assert_eq!(
+            Err(FrequencyError::InvalidFrequency(f64::INFINITY)),
+            parameters.try_characteristic_impedance(f64::INFINITY, 0.008)
+        );
+        assert!(parameters
+            .try_characteristic_impedance(f64::NAN, 0.008)
+            .is_err());
+        assert_eq!(
+            Ok(parameters.propagation_constant(440.0, 0.008)),
+            parameters.try_propagation_constant(440.0, 0.008)
+        );
+    }
+
+    #[test]
+    fn it_can_invert_the_helpers() {
+        let parameters = ParametersBuilder::new().build();
+
+        let impedance = parameters.wave_impedance(0.0075);
+        assert!((parameters.radius_from_wave_impedance(impedance) - 0.0075).abs() < 1e-15);
+
+        let epsilon = parameters.get_epsilon_from_f(523.25, 0.0075);
+        assert!((parameters.frequency_from_epsilon(epsilon, 0.0075) - 523.25).abs() < 1e-9);
+        assert!((parameters.radius_from_epsilon(epsilon, 523.25) - 0.0075).abs() < 1e-15);
+
+        let wave_number = parameters.wave_number(523.25);
+        assert!((parameters.frequency(wave_number) - 523.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn it_can_be_shared_across_threads() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PhysicalParameters>();
+
+        let parameters = Arc::new(ParametersBuilder::new().build());
+        let expected = parameters.wave_impedance(0.008);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let parameters = Arc::clone(&parameters);
+                thread::spawn(move || parameters.wave_impedance(0.008))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(expected, handle.join().unwrap());
+        }
+    }
+
+    #[test]
+    fn it_can_build_from_altitude() {
+        let parameters = ParametersBuilder::new().with_altitude(1000.0).build();
+        assert_eq!(pressure_at(1000.0), parameters.pressure);
+
+        let parameters = ParametersBuilder::new()
+            .with_altitude_from_sea_level(0.0, 98.7)
+            .build();
+        assert_eq!(98.7, parameters.pressure);
+    }
+
+    #[test]
+    fn it_validates_inputs() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(37.0, TemperatureType::C)
+            .with_humidity_saturation(100.0)
+            .with_molar_co2(0.04)
+            .try_build()
+            .unwrap();
+        assert_eq!(363.99110139436897, parameters.sound_speed);
+
+        let actual = ParametersBuilder::new().with_pressure(-1.0).try_build();
+        assert_eq!(Some(ParameterError::PressureOutOfRange(-1.0)), actual.err());
+
+        let actual = ParametersBuilder::new()
+            .with_humidity_saturation(120.0)
+            .try_build();
+        assert_eq!(
+            Some(ParameterError::HumiditySaturationOutOfRange(120.0)),
+            actual.err()
+        );
+
+        let actual = ParametersBuilder::new().with_molar_co2(1.5).try_build();
+        assert_eq!(Some(ParameterError::MolarCo2OutOfRange(1.5)), actual.err());
+
+        let actual = ParametersBuilder::new()
+            .with_temperature(f64::NAN, TemperatureType::C)
+            .try_build();
+        assert!(matches!(
+            actual.err(),
+            Some(ParameterError::TemperatureOutOfRange(value)) if value.is_nan()
+        ));
+    }
+
+    #[test]
+    fn it_reports_missing_and_boiling_inputs() {
+        let actual = ParametersBuilder::default().try_build();
+        assert_eq!(Some(ParameterError::MissingTemperature), actual.err());
+
+        let actual = ParametersBuilder::new()
+            .with_temperature(100.0, TemperatureType::C)
+            .with_humidity_saturation(100.0)
+            .try_build();
+        assert!(matches!(
+            actual.err(),
+            Some(ParameterError::CompositionOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn it_can_build_from_any_pressure_unit() {
+        let parameters = ParametersBuilder::new()
+            .with_pressure_in(1013.25, PressureType::HPa)
+            .build();
+        assert_eq!(101.325, parameters.pressure);
+    }
+
+    #[test]
+    fn it_can_build_from_any_humidity_measure() {
+        let parameters = ParametersBuilder::new()
+            .with_temperature(37.0, TemperatureType::C)
+            .with_dew_point(37.0, TemperatureType::C)
+            .build();
+        assert!((parameters.humidity_saturation - 100.0).abs() < 1e-12);
+
+        let parameters = ParametersBuilder::new()
+            .with_molar_water_vapour(0.01)
+            .build();
+        assert!((parameters.molar_water_vapour - 0.01).abs() < 1e-15);
+
+        let actual = ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .with_dew_point(25.0, TemperatureType::C)
+            .try_build();
+        assert!(matches!(
+            actual.err(),
+            Some(ParameterError::HumiditySaturationOutOfRange(_))
+        ));
+    }
+
+    #[test]
+    fn it_saves_builder_inputs() {
+        let builder = ParametersBuilder::new()
+            .with_temperature(37.0, TemperatureType::C)
+            .with_dew_point(30.0, TemperatureType::C)
+            .with_model(AirModel::Cramer1993);
+
+        let json = serde_json::to_string(&builder).unwrap();
+        let loaded: ParametersBuilder = serde_json::from_str(&json).unwrap();
+        assert_eq!(builder, loaded);
+        assert_eq!(builder.build(), loaded.build());
+
+        let text = toml::to_string(&builder).unwrap();
+        let loaded: ParametersBuilder = toml::from_str(&text).unwrap();
+        assert_eq!(builder, loaded);
+        assert!(text.contains("humidity_type = \"dew_point\""));
+        assert!(text.contains("model = \"cramer1993\""));
+    }
+
+    #[test]
+    fn it_loads_builder_inputs_with_defaults() {
+        let loaded: ParametersBuilder = toml::from_str(
+            "temperature = 293.15\npressure = 101.325\nmolar_co2 = 0.00039\nhumidity = 100.0\n",
+        )
+        .unwrap();
+        assert_eq!(348.3266788996193, loaded.build().sound_speed);
+
+        let loaded: Result<ParametersBuilder, _> = toml::from_str("temprature = 293.15\n");
+        assert!(loaded.is_err());
+    }
+
+    #[test]
+    fn it_saves_derived_properties_for_audit() {
+        let parameters = ParametersBuilder::new().build();
+
+        let json = serde_json::to_string(&parameters).unwrap();
+        let loaded: PhysicalParameters = serde_json::from_str(&json).unwrap();
+        assert_eq!(parameters, loaded);
+
+        let text = toml::to_string(&parameters).unwrap();
+        let loaded: PhysicalParameters = toml::from_str(&text).unwrap();
+        assert_eq!(parameters, loaded);
+    }
 }
@@ -0,0 +1,60 @@
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PressureType {
+    KPa,
+    HPa, // Same as mbar
+    Pa,
+    Atm,
+    MmHg,
+    InHg,
+    Psi,
+}
+
+// Size of one non-metric unit, in kPa.
+const KPA_PER_ATM: f64 = 101.325;
+const KPA_PER_MMHG: f64 = 0.133322387415;
+const KPA_PER_INHG: f64 = 3.38638864;
+const KPA_PER_PSI: f64 = 6.894757293168;
+
+pub fn normalize_pressure(pressure: f64, pressure_type: PressureType) -> f64 {
+    return match pressure_type {
+        PressureType::KPa => pressure,
+        PressureType::HPa => pressure / 10.0,
+        PressureType::Pa => pressure / 1000.0,
+        PressureType::Atm => pressure * KPA_PER_ATM,
+        PressureType::MmHg => pressure * KPA_PER_MMHG,
+        PressureType::InHg => pressure * KPA_PER_INHG,
+        PressureType::Psi => pressure * KPA_PER_PSI,
+    };
+}
+
+pub fn denormalize_pressure(pressure: f64, pressure_type: PressureType) -> f64 {
+    return match pressure_type {
+        PressureType::KPa => pressure,
+        PressureType::HPa => pressure * 10.0,
+        PressureType::Pa => pressure * 1000.0,
+        PressureType::Atm => pressure / KPA_PER_ATM,
+        PressureType::MmHg => pressure / KPA_PER_MMHG,
+        PressureType::InHg => pressure / KPA_PER_INHG,
+        PressureType::Psi => pressure / KPA_PER_PSI,
+    };
+}
+
+impl fmt::Display for PressureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            PressureType::KPa => "kPa",
+            PressureType::HPa => "hPa",
+            PressureType::Pa => "Pa",
+            PressureType::Atm => "atm",
+            PressureType::MmHg => "mmHg",
+            PressureType::InHg => "inHg",
+            PressureType::Psi => "psi",
+        };
+        return write!(f, "{}", symbol);
+    }
+}
+
+#[cfg(test)]
+mod pressure_tests;
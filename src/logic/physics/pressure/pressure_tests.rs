@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod pressure_tests {
+    use super::super::*;
+
+    #[test]
+    fn it_can_normalize_any_unit() {
+        assert_eq!(101.325, normalize_pressure(101.325, PressureType::KPa));
+        assert_eq!(101.325, normalize_pressure(1013.25, PressureType::HPa));
+        assert_eq!(101.325, normalize_pressure(101325.0, PressureType::Pa));
+        assert_eq!(101.325, normalize_pressure(1.0, PressureType::Atm));
+        assert!((normalize_pressure(760.0, PressureType::MmHg) - 101.325).abs() < 1e-4);
+        assert!((normalize_pressure(29.9213, PressureType::InHg) - 101.325).abs() < 1e-3);
+        assert!((normalize_pressure(14.6959, PressureType::Psi) - 101.325).abs() < 1e-3);
+    }
+
+    #[test]
+    fn it_can_convert_back_for_display() {
+        let actual = denormalize_pressure(
+            normalize_pressure(30.12, PressureType::InHg),
+            PressureType::InHg,
+        );
+        assert!((actual - 30.12).abs() < 1e-12);
+        assert!((denormalize_pressure(101.325, PressureType::HPa) - 1013.25).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,200 @@
+use std::ops::RangeInclusive;
+
+use crate::structs::parameters::{ParameterError, ParametersBuilder, PhysicalParameters};
+
+use super::{
+    pressure::{denormalize_pressure, PressureType},
+    temperature::{denormalize_temperature, TemperatureType},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ReportFormat {
+    Csv,
+    Markdown,
+}
+
+// Evenly spaced values from the start to the end of a range, both included.
+pub fn sweep(range: RangeInclusive<f64>, steps: usize) -> Vec<f64> {
+    let (start, end) = (*range.start(), *range.end());
+    if steps < 2 {
+        return vec![start];
+    }
+    return (0..steps)
+        .map(|index| start + (end - start) * index as f64 / (steps - 1) as f64)
+        .collect();
+}
+
+// Table of air properties over every combination of the swept inputs.
+// Inputs that are not swept keep the value of the base builder.
+//
+// Rows are ordered by temperature, then humidity, pressure and CO2, and list
+// temperature and pressure in the units they were given in.
+#[derive(Debug, Clone)]
+pub struct AirPropertyReport {
+    base: ParametersBuilder,
+    temperatures: Vec<f64>,
+    temperature_type: TemperatureType,
+    humidities: Vec<f64>, // % of saturation
+    pressures: Vec<f64>,
+    pressure_type: PressureType,
+    molar_co2s: Vec<f64>, // mol/mol
+    precision: Option<usize>,
+}
+
+impl AirPropertyReport {
+    pub fn new(base: ParametersBuilder) -> Self {
+        return Self {
+            base,
+            temperatures: vec![],
+            temperature_type: TemperatureType::C,
+            humidities: vec![],
+            pressures: vec![],
+            pressure_type: PressureType::KPa,
+            molar_co2s: vec![],
+            precision: None,
+        };
+    }
+
+    pub fn with_temperatures(mut self, temperatures: Vec<f64>, unit: TemperatureType) -> Self {
+        self.temperatures = temperatures;
+        self.temperature_type = unit;
+        return self;
+    }
+
+    pub fn with_humidity_saturations(mut self, humidities: Vec<f64>) -> Self {
+        self.humidities = humidities;
+        return self;
+    }
+
+    pub fn with_pressures(mut self, pressures: Vec<f64>, unit: PressureType) -> Self {
+        self.pressures = pressures;
+        self.pressure_type = unit;
+        return self;
+    }
+
+    pub fn with_molar_co2s(mut self, molar_co2s: Vec<f64>) -> Self {
+        self.molar_co2s = molar_co2s;
+        return self;
+    }
+
+    // Number of decimals of every value, or the shortest exact form if not set.
+    // Columns that are always far below 1, the molar fractions, viscosity and
+    // conductivity, are written in scientific notation so they keep that many
+    // significant decimals; every other column is written in fixed notation.
+    pub fn with_precision(mut self, decimals: usize) -> Self {
+        self.precision = Some(decimals);
+        return self;
+    }
+
+    // Values of a swept input, or a single None to keep the base value.
+    fn axis(values: &[f64]) -> Vec<Option<f64>> {
+        if values.is_empty() {
+            return vec![None];
+        }
+        return values.iter().copied().map(Some).collect();
+    }
+
+    pub fn rows(&self) -> Result<Vec<PhysicalParameters>, ParameterError> {
+        let mut rows = vec![];
+        for temperature in Self::axis(&self.temperatures) {
+            for humidity in Self::axis(&self.humidities) {
+                for pressure in Self::axis(&self.pressures) {
+                    for molar_co2 in Self::axis(&self.molar_co2s) {
+                        let mut builder = self.base;
+                        if let Some(temperature) = temperature {
+                            builder = builder.with_temperature(temperature, self.temperature_type);
+                        }
+                        if let Some(humidity) = humidity {
+                            builder = builder.with_humidity_saturation(humidity);
+                        }
+                        if let Some(pressure) = pressure {
+                            builder = builder.with_pressure_in(pressure, self.pressure_type);
+                        }
+                        if let Some(molar_co2) = molar_co2 {
+                            builder = builder.with_molar_co2(molar_co2);
+                        }
+                        rows.push(builder.try_build()?);
+                    }
+                }
+            }
+        }
+        return Ok(rows);
+    }
+
+    fn header(&self) -> Vec<String> {
+        return vec![
+            format!("Temperature ({})", self.temperature_type),
+            "Humidity (%)".to_string(),
+            format!("Pressure ({})", self.pressure_type),
+            "CO2 (mol/mol)".to_string(),
+            "Water vapour (mol/mol)".to_string(),
+            "Density (kg/m^3)".to_string(),
+            "Viscosity (kg/(m.s))".to_string(),
+            "cp (J/(kg.K))".to_string(),
+            "Gamma".to_string(),
+            "Conductivity (W/(m.K))".to_string(),
+            "Prandtl".to_string(),
+            "Sound speed (m/s)".to_string(),
+        ];
+    }
+
+    fn cells(&self, parameters: &PhysicalParameters) -> Vec<String> {
+        // (value, written in scientific notation)
+        let values = [
+            (
+                denormalize_temperature(parameters.temperature, self.temperature_type),
+                false,
+            ),
+            (parameters.humidity_saturation, false),
+            (
+                denormalize_pressure(parameters.pressure, self.pressure_type),
+                false,
+            ),
+            (parameters.molar_co2, true),
+            (parameters.molar_water_vapour, true),
+            (parameters.air_density, false),
+            (parameters.dynamic_viscosity, true),
+            (parameters.specific_heat, false),
+            (parameters.specific_heats_ratio, false),
+            (parameters.thermal_conductivity, true),
+            (parameters.prandtl_number, false),
+            (parameters.sound_speed, false),
+        ];
+        return values
+            .iter()
+            .map(|(value, scientific)| match self.precision {
+                Some(decimals) if *scientific => format!("{:.*e}", decimals, value),
+                Some(decimals) => format!("{:.*}", decimals, value),
+                None => value.to_string(),
+            })
+            .collect();
+    }
+
+    pub fn to_csv(&self) -> Result<String, ParameterError> {
+        let mut output = self.header().join(",") + "\n";
+        for row in self.rows()? {
+            output += &(self.cells(&row).join(",") + "\n");
+        }
+        return Ok(output);
+    }
+
+    pub fn to_markdown(&self) -> Result<String, ParameterError> {
+        let header = self.header();
+        let mut output = format!("| {} |\n", header.join(" | "));
+        output += &format!("|{}\n", "---:|".repeat(header.len()));
+        for row in self.rows()? {
+            output += &format!("| {} |\n", self.cells(&row).join(" | "));
+        }
+        return Ok(output);
+    }
+
+    pub fn render(&self, format: ReportFormat) -> Result<String, ParameterError> {
+        return match format {
+            ReportFormat::Csv => self.to_csv(),
+            ReportFormat::Markdown => self.to_markdown(),
+        };
+    }
+}
+
+#[cfg(test)]
+mod report_tests;
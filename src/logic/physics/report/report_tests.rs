@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod report_tests {
+    use crate::structs::parameters::{ParameterError, ParametersBuilder};
+
+    use super::super::*;
+
+    #[test]
+    fn it_sweeps_ranges() {
+        assert_eq!(vec![10.0, 15.0, 20.0], sweep(10.0..=20.0, 3));
+        assert_eq!(vec![10.0], sweep(10.0..=20.0, 1));
+    }
+
+    #[test]
+    fn it_covers_every_combination() {
+        let report = AirPropertyReport::new(ParametersBuilder::new())
+            .with_temperatures(sweep(0.0..=30.0, 4), TemperatureType::C)
+            .with_humidity_saturations(vec![0.0, 100.0])
+            .with_molar_co2s(vec![0.0004, 0.04]);
+        let rows = report.rows().unwrap();
+        assert_eq!(16, rows.len());
+        assert_eq!(101.325, rows[0].pressure);
+        assert_eq!(273.15, rows[0].temperature);
+        assert_eq!(0.04, rows[1].molar_co2);
+        assert_eq!(100.0, rows[2].humidity_saturation);
+        assert_eq!(303.15, rows[15].temperature);
+    }
+
+    #[test]
+    fn it_writes_csv_in_the_entered_units() {
+        let csv = AirPropertyReport::new(ParametersBuilder::new())
+            .with_temperatures(vec![68.0], TemperatureType::F)
+            .with_pressures(vec![1013.25], PressureType::HPa)
+            .with_humidity_saturations(vec![0.0])
+            .with_precision(2)
+            .to_csv()
+            .unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(2, lines.len());
+        assert!(lines[0].starts_with("Temperature (°F),Humidity (%),Pressure (hPa),"));
+        assert!(lines[0].ends_with(",Sound speed (m/s)"));
+        let cells: Vec<&str> = lines[1].split(',').collect();
+        assert_eq!(
+            vec!["68.00", "0.00", "1013.25", "3.90e-4", "0.00e0"],
+            cells[..5]
+        );
+        assert_eq!("1.82e-5", cells[6]); // Viscosity
+        assert_eq!("343.29", cells[11]);
+    }
+
+    #[test]
+    fn it_keeps_small_temperatures_and_humidities_in_fixed_notation() {
+        let csv = AirPropertyReport::new(ParametersBuilder::new())
+            .with_temperatures(vec![0.5], TemperatureType::C)
+            .with_humidity_saturations(vec![0.5])
+            .with_precision(2)
+            .to_csv()
+            .unwrap();
+        let line = csv.lines().nth(1).unwrap();
+        let cells: Vec<&str> = line.split(',').collect();
+        assert_eq!(vec!["0.50", "0.50"], cells[..2]);
+        assert!(cells[4].contains('e')); // Water vapour
+        assert!(!cells[10].contains('e')); // Prandtl, below 1 but not always small
+    }
+
+    #[test]
+    fn it_writes_markdown_tables() {
+        let report = AirPropertyReport::new(ParametersBuilder::new())
+            .with_temperatures(vec![20.0], TemperatureType::C)
+            .with_humidity_saturations(vec![0.0]);
+        let markdown = report.render(ReportFormat::Markdown).unwrap();
+        let lines: Vec<&str> = markdown.lines().collect();
+        assert_eq!(3, lines.len());
+        assert_eq!(13, lines[1].matches('|').count());
+        assert!(lines[2].ends_with("| 343.2878525006776 |"));
+    }
+
+    #[test]
+    fn it_rejects_invalid_inputs() {
+        let report = AirPropertyReport::new(ParametersBuilder::new())
+            .with_humidity_saturations(vec![50.0, 150.0]);
+        assert_eq!(
+            Err(ParameterError::HumiditySaturationOutOfRange(150.0)),
+            report.to_csv()
+        );
+    }
+}
@@ -0,0 +1,171 @@
+use std::f64::consts::LN_2;
+
+use super::{
+    calcs::AirState,
+    models::{AirPropertyModel, Cipm2007},
+};
+
+// Central-difference steps for each input, small enough for the curvature of the
+// CIPM-2007 and Tsilingiris fits to be negligible, large enough to avoid round-off.
+const TEMPERATURE_STEP: f64 = 1e-2; // K
+const PRESSURE_STEP: f64 = 1e-2; // kPa
+const HUMIDITY_SATURATION_STEP: f64 = 1e-2; // %
+const MOLAR_CO2_STEP: f64 = 1e-5; // mol/mol
+
+pub const CENTS_PER_OCTAVE: f64 = 1200.0;
+
+// Partial derivatives of one property with respect to each input.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sensitivity {
+    pub temperature: f64,         // Change per K
+    pub pressure: f64,            // Change per kPa
+    pub humidity_saturation: f64, // Change per % of saturation
+    pub molar_co2: f64,           // Change per mol/mol of CO2
+}
+
+impl Sensitivity {
+    fn of(state: &AirState, model: &dyn AirPropertyModel, property: fn(&AirState) -> f64) -> Self {
+        let (pressure, temperature, humidity_saturation, molar_co2) = (
+            state.pressure,
+            state.temperature,
+            state.humidity_saturation,
+            state.molar_co2,
+        );
+        let derivative = |step: f64, forward: AirState, backward: AirState| {
+            return (property(&forward.with_model(model)) - property(&backward.with_model(model)))
+                / (2.0 * step);
+        };
+        return Self {
+            temperature: derivative(
+                TEMPERATURE_STEP,
+                AirState::new(
+                    pressure,
+                    temperature + TEMPERATURE_STEP,
+                    humidity_saturation,
+                    molar_co2,
+                ),
+                AirState::new(
+                    pressure,
+                    temperature - TEMPERATURE_STEP,
+                    humidity_saturation,
+                    molar_co2,
+                ),
+            ),
+            pressure: derivative(
+                PRESSURE_STEP,
+                AirState::new(
+                    pressure + PRESSURE_STEP,
+                    temperature,
+                    humidity_saturation,
+                    molar_co2,
+                ),
+                AirState::new(
+                    pressure - PRESSURE_STEP,
+                    temperature,
+                    humidity_saturation,
+                    molar_co2,
+                ),
+            ),
+            humidity_saturation: derivative(
+                HUMIDITY_SATURATION_STEP,
+                AirState::new(
+                    pressure,
+                    temperature,
+                    humidity_saturation + HUMIDITY_SATURATION_STEP,
+                    molar_co2,
+                ),
+                AirState::new(
+                    pressure,
+                    temperature,
+                    humidity_saturation - HUMIDITY_SATURATION_STEP,
+                    molar_co2,
+                ),
+            ),
+            molar_co2: derivative(
+                MOLAR_CO2_STEP,
+                AirState::new(
+                    pressure,
+                    temperature,
+                    humidity_saturation,
+                    molar_co2 + MOLAR_CO2_STEP,
+                ),
+                AirState::new(
+                    pressure,
+                    temperature,
+                    humidity_saturation,
+                    molar_co2 - MOLAR_CO2_STEP,
+                ),
+            ),
+        };
+    }
+
+    fn scale(self, factor: f64) -> Self {
+        return Self {
+            temperature: self.temperature * factor,
+            pressure: self.pressure * factor,
+            humidity_saturation: self.humidity_saturation * factor,
+            molar_co2: self.molar_co2 * factor,
+        };
+    }
+}
+
+// Sensitivities of the air properties at one state.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AirSensitivities {
+    pub state: AirState,
+    pub sound_speed: Sensitivity,      // m/s per unit of input
+    pub air_density: Sensitivity,      // kg/m^3 per unit of input
+    pub wave_number: Sensitivity,      // rad/m at 1 Hz per unit of input
+    pub epsilon_constant: Sensitivity, // per unit of input
+    pub alpha_constant: Sensitivity,   // per unit of input
+}
+
+impl AirSensitivities {
+    // Drift in cents of every resonance of a fixed bore, per unit of input.
+    // Resonance frequencies scale with sound speed when losses are neglected.
+    pub fn pitch(&self) -> Sensitivity {
+        return self
+            .sound_speed
+            .scale(CENTS_PER_OCTAVE / (LN_2 * self.state.sound_speed));
+    }
+}
+
+// Sensitivities with the CIPM-2007 sound speed, the default AirModel.
+pub fn calculate_sensitivities(
+    pressure: f64,
+    temperature: f64,
+    humidity_saturation: f64,
+    molar_co2: f64,
+) -> AirSensitivities {
+    return calculate_sensitivities_with(
+        pressure,
+        temperature,
+        humidity_saturation,
+        molar_co2,
+        &Cipm2007,
+    );
+}
+
+// Same as calculate_sensitivities, with the sound speed of another model, such as the
+// one selected on a ParametersBuilder.
+pub fn calculate_sensitivities_with(
+    pressure: f64,
+    temperature: f64,
+    humidity_saturation: f64,
+    molar_co2: f64,
+    model: &dyn AirPropertyModel,
+) -> AirSensitivities {
+    let state =
+        AirState::new(pressure, temperature, humidity_saturation, molar_co2).with_model(model);
+    return AirSensitivities {
+        state,
+        sound_speed: Sensitivity::of(&state, model, |state| state.sound_speed),
+        air_density: Sensitivity::of(&state, model, |state| state.air_density),
+        wave_number: Sensitivity::of(&state, model, |state| state.wave_number),
+        epsilon_constant: Sensitivity::of(&state, model, |state| state.epsilon_constant),
+        alpha_constant: Sensitivity::of(&state, model, |state| state.alpha_constant),
+    };
+}
+
+#[cfg(test)]
+mod sensitivity_tests;
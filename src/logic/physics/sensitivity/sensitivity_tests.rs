@@ -0,0 +1,53 @@
+#[cfg(test)]
+mod sensitivity_tests {
+    use crate::logic::physics::models::{AirModel, Cramer1993, IdealDryAir};
+
+    use super::super::*;
+
+    #[test]
+    fn it_matches_the_ideal_gas_temperature_dependence() {
+        let actual = calculate_sensitivities(101.325, 293.15, 0.0, 0.00039);
+        // For dry air c ~ sqrt(T), so dc/dT ~ c / 2T.
+        let expected = actual.state.sound_speed / (2.0 * 293.15);
+        assert!((actual.sound_speed.temperature - expected).abs() / expected < 0.01);
+        // and rho ~ p / T.
+        let expected = actual.state.air_density / 101.325;
+        assert!((actual.air_density.pressure - expected).abs() / expected < 0.01);
+        let expected =
+            -actual.state.wave_number / actual.state.sound_speed * actual.sound_speed.temperature;
+        assert!((actual.wave_number.temperature - expected).abs() / expected.abs() < 1e-6);
+    }
+
+    #[test]
+    fn it_reports_pitch_drift_in_cents() {
+        let actual = calculate_sensitivities(101.325, 293.15, 45.0, 0.00039).pitch();
+        // About 3 cents sharp per degree for the gas itself, and more at constant
+        // relative humidity as warmer air holds more water vapour.
+        assert!(actual.temperature > 3.5 && actual.temperature < 3.8);
+        assert!(actual.humidity_saturation > 0.0);
+        assert!(actual.molar_co2 < 0.0);
+        assert!(actual.pressure < 0.0 && actual.pressure > -0.2);
+    }
+
+    #[test]
+    fn it_follows_the_sound_speed_model() {
+        let actual = calculate_sensitivities_with(101.325, 293.15, 45.0, 0.00039, &IdealDryAir);
+        assert_eq!(0.0, actual.sound_speed.humidity_saturation);
+        assert_eq!(0.0, actual.sound_speed.pressure);
+        let expected = actual.state.sound_speed / (2.0 * 293.15);
+        assert!((actual.sound_speed.temperature - expected).abs() / expected < 1e-6);
+
+        let default = calculate_sensitivities(101.325, 293.15, 45.0, 0.00039);
+        let cipm = calculate_sensitivities_with(
+            101.325,
+            293.15,
+            45.0,
+            0.00039,
+            AirModel::default().model(),
+        );
+        assert_eq!(default, cipm);
+        let cramer = calculate_sensitivities_with(101.325, 293.15, 45.0, 0.00039, &Cramer1993);
+        assert_ne!(default.sound_speed, cramer.sound_speed);
+        assert!(cramer.pitch().temperature > 3.0 && cramer.pitch().temperature < 3.8);
+    }
+}
@@ -0,0 +1,366 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
+
+use crate::structs::parameters::{ParameterError, ParametersBuilder, PhysicalParameters};
+
+use super::temperature::TemperatureType;
+
+// Largest number of grid lines per axis tried by ParametersTable::with_tolerance.
+const MAX_STEPS: usize = 1025;
+
+// Margin on the second derivatives sampled in each cell, covering their variation
+// between the samples. The CIPM-2007 and Tsilingiris fits are smooth polynomials and
+// exponentials whose curvature changes by a few percent across a cell at most.
+const CURVATURE_SAFETY_FACTOR: f64 = 2.0;
+
+// Derived properties covered by the error bound, other than water vapour.
+const FIELDS: [fn(&PhysicalParameters) -> f64; 10] = [
+    |p| p.air_density,
+    |p| p.dynamic_viscosity,
+    |p| p.specific_heat,
+    |p| p.specific_heats_ratio,
+    |p| p.thermal_conductivity,
+    |p| p.prandtl_number,
+    |p| p.sound_speed,
+    |p| p.epsilon_constant,
+    |p| p.alpha_constant,
+    |p| p.wave_number,
+];
+
+// Source of physical parameters over temperature and humidity, for a fixed
+// pressure, CO2 fraction and air model.
+pub trait ParametersProvider {
+    // Parameters at a temperature in Kelvin and a relative humidity in %.
+    fn parameters(&self, temperature: f64, humidity_saturation: f64) -> PhysicalParameters;
+}
+
+// Exact path, building the parameters on every call.
+pub struct ExactParameters {
+    base: ParametersBuilder,
+}
+
+impl ExactParameters {
+    pub fn new(base: ParametersBuilder) -> Self {
+        return Self { base };
+    }
+}
+
+impl ParametersProvider for ExactParameters {
+    fn parameters(&self, temperature: f64, humidity_saturation: f64) -> PhysicalParameters {
+        return self
+            .base
+            .with_temperature(temperature, TemperatureType::K)
+            .with_humidity_saturation(humidity_saturation)
+            .build();
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TableError {
+    InvalidRange,               // Empty or non-finite temperature or humidity range
+    TooFewSteps,                // Fewer than two grid lines on an axis
+    ToleranceNotReached(f64),   // Smallest error bound reached with the largest grid
+    Parameters(ParameterError), // A grid node is outside the valid inputs
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TableError::InvalidRange => write!(f, "table ranges must be finite and non-empty"),
+            TableError::TooFewSteps => write!(f, "tables need at least two steps per axis"),
+            TableError::ToleranceNotReached(error) => {
+                write!(f, "tolerance not reached, best relative error is {}", error)
+            }
+            TableError::Parameters(error) => write!(f, "invalid table node: {}", error),
+        };
+    }
+}
+
+impl Error for TableError {}
+
+#[derive(Debug, Clone, Copy)]
+struct Axis {
+    start: f64,
+    end: f64,
+    steps: usize,
+}
+
+impl Axis {
+    fn new(range: &RangeInclusive<f64>, steps: usize) -> Result<Self, TableError> {
+        let (start, end) = (*range.start(), *range.end());
+        if !start.is_finite() || !end.is_finite() || start >= end {
+            return Err(TableError::InvalidRange);
+        }
+        if steps < 2 {
+            return Err(TableError::TooFewSteps);
+        }
+        return Ok(Self { start, end, steps });
+    }
+
+    fn value(&self, index: f64) -> f64 {
+        return self.start + (self.end - self.start) * index / (self.steps - 1) as f64;
+    }
+
+    // Cell index and fractional position within the cell, or None outside the axis.
+    fn locate(&self, value: f64) -> Option<(usize, f64)> {
+        if !(self.start..=self.end).contains(&value) {
+            return None;
+        }
+        let position = (value - self.start) / (self.end - self.start) * (self.steps - 1) as f64;
+        let index = (position.floor() as usize).min(self.steps - 2);
+        return Some((index, position - index as f64));
+    }
+}
+
+// Weighted sum of the derived properties of several parameter sets.
+fn blend(nodes: [(&PhysicalParameters, f64); 4]) -> PhysicalParameters {
+    let sum = |field: fn(&PhysicalParameters) -> f64| -> f64 {
+        return nodes
+            .iter()
+            .map(|(node, weight)| weight * field(node))
+            .sum();
+    };
+    return PhysicalParameters {
+        temperature: sum(|p| p.temperature),
+        pressure: sum(|p| p.pressure),
+        molar_co2: sum(|p| p.molar_co2),
+        molar_water_vapour: sum(|p| p.molar_water_vapour),
+        humidity_saturation: sum(|p| p.humidity_saturation),
+        air_density: sum(|p| p.air_density),
+        dynamic_viscosity: sum(|p| p.dynamic_viscosity),
+        specific_heat: sum(|p| p.specific_heat),
+        specific_heats_ratio: sum(|p| p.specific_heats_ratio),
+        thermal_conductivity: sum(|p| p.thermal_conductivity),
+        prandtl_number: sum(|p| p.prandtl_number),
+        sound_speed: sum(|p| p.sound_speed),
+        epsilon_constant: sum(|p| p.epsilon_constant),
+        alpha_constant: sum(|p| p.alpha_constant),
+        wave_number: sum(|p| p.wave_number),
+    };
+}
+
+// Relative error bound of one cell, split into the terms of each axis and the mixed term.
+#[derive(Debug, Clone, Copy, Default)]
+struct ErrorBound {
+    temperature: f64,
+    humidity: f64,
+    mixed: f64,
+}
+
+impl ErrorBound {
+    fn total(&self) -> f64 {
+        return self.temperature + self.humidity + self.mixed;
+    }
+
+    fn max(self, other: Self) -> Self {
+        return Self {
+            temperature: self.temperature.max(other.temperature),
+            humidity: self.humidity.max(other.humidity),
+            mixed: self.mixed.max(other.mixed),
+        };
+    }
+}
+
+// Parameters precomputed on a regular temperature and humidity grid, and
+// interpolated bilinearly in between.
+//
+// The error bound of bilinear interpolation over a cell of sides hT and hH is
+//
+//     |f - p| <= hT^2/8 max|f_TT| + hH^2/8 max|f_HH| + hT.hH/4 max|f_TH|
+//
+// taken relative to the smallest |f| in the cell, for every derived property, and
+// maximised over the cells. The second derivatives are the largest of the central
+// differences on a 3x3 grid spanning each cell, times CURVATURE_SAFETY_FACTOR.
+//
+// Water vapour vanishes in dry air, so it has no relative error bound of this form.
+// It is proportional to humidity instead: its humidity interpolation is exact, and
+// its relative error along temperature is the same on every row of a cell, so it is
+// bounded from the temperature term of the most humid row alone.
+pub struct ParametersTable {
+    base: ParametersBuilder,
+    temperatures: Axis,
+    humidities: Axis,
+    nodes: Vec<PhysicalParameters>, // Row-major, one row per temperature
+    error_bound: ErrorBound,
+}
+
+impl ParametersTable {
+    // Table over temperatures in Kelvin and relative humidities in %, with the
+    // number of grid lines along each axis.
+    pub fn new(
+        base: ParametersBuilder,
+        temperature_range: RangeInclusive<f64>,
+        temperature_steps: usize,
+        humidity_range: RangeInclusive<f64>,
+        humidity_steps: usize,
+    ) -> Result<Self, TableError> {
+        let mut table = Self {
+            base,
+            temperatures: Axis::new(&temperature_range, temperature_steps)?,
+            humidities: Axis::new(&humidity_range, humidity_steps)?,
+            nodes: Vec::with_capacity(temperature_steps * humidity_steps),
+            error_bound: ErrorBound::default(),
+        };
+        for i in 0..temperature_steps {
+            for j in 0..humidity_steps {
+                let node = table.exact(i as f64, j as f64)?;
+                table.nodes.push(node);
+            }
+        }
+        table.error_bound = table.error_bound()?;
+        return Ok(table);
+    }
+
+    // Smallest table, doubling the grid density of whichever axis needs it, whose
+    // error bound is within a relative tolerance.
+    pub fn with_tolerance(
+        base: ParametersBuilder,
+        temperature_range: RangeInclusive<f64>,
+        humidity_range: RangeInclusive<f64>,
+        tolerance: f64,
+    ) -> Result<Self, TableError> {
+        let (mut temperature_steps, mut humidity_steps) = (2, 2);
+        loop {
+            let table = Self::new(
+                base,
+                temperature_range.clone(),
+                temperature_steps,
+                humidity_range.clone(),
+                humidity_steps,
+            )?;
+            if table.max_relative_error() <= tolerance {
+                return Ok(table);
+            }
+            // Halving a cell side quarters its axis term and halves the mixed term.
+            let bound = table.error_bound;
+            let refine_temperature = bound.temperature + 0.5 * bound.mixed > 0.5 * tolerance;
+            let refine_humidity =
+                bound.humidity + 0.5 * bound.mixed > 0.5 * tolerance || !refine_temperature;
+            if (refine_temperature && temperature_steps >= MAX_STEPS)
+                || (refine_humidity && humidity_steps >= MAX_STEPS)
+            {
+                return Err(TableError::ToleranceNotReached(table.max_relative_error()));
+            }
+            if refine_temperature {
+                temperature_steps = 2 * temperature_steps - 1;
+            }
+            if refine_humidity {
+                humidity_steps = 2 * humidity_steps - 1;
+            }
+        }
+    }
+
+    // Exact parameters at fractional grid indices.
+    fn exact(&self, i: f64, j: f64) -> Result<PhysicalParameters, TableError> {
+        return self
+            .base
+            .with_temperature(self.temperatures.value(i), TemperatureType::K)
+            .with_humidity_saturation(self.humidities.value(j))
+            .try_build()
+            .map_err(TableError::Parameters);
+    }
+
+    // Error bound of one cell, from exact values on a 3x3 grid over it.
+    fn cell_error_bound(&self, i: usize, j: usize) -> Result<ErrorBound, TableError> {
+        let mut grid = vec![];
+        for u in [0.0, 0.5, 1.0] {
+            for v in [0.0, 0.5, 1.0] {
+                grid.push(self.exact(i as f64 + u, j as f64 + v)?);
+            }
+        }
+        // With steps of half a cell, hT^2/8 |f_TT| is half the second difference.
+        let second_difference =
+            |values: [f64; 3]| 0.5 * (values[0] - 2.0 * values[1] + values[2]).abs();
+
+        let water_vapour = |u: usize| grid[3 * u + 2].molar_water_vapour;
+        let wettest = (0..3).map(water_vapour).fold(f64::INFINITY, f64::min);
+        let mut bound = ErrorBound::default();
+        if wettest > 0.0 {
+            bound.temperature = CURVATURE_SAFETY_FACTOR
+                * second_difference([water_vapour(0), water_vapour(1), water_vapour(2)])
+                / wettest;
+        }
+        for field in FIELDS {
+            let f = |u: usize, v: usize| field(&grid[3 * u + v]);
+            let smallest = (0..9)
+                .map(|index| f(index / 3, index % 3).abs())
+                .fold(f64::INFINITY, f64::min);
+            let scale = CURVATURE_SAFETY_FACTOR / smallest;
+            bound = bound.max(ErrorBound {
+                temperature: scale
+                    * (0..3)
+                        .map(|v| second_difference([f(0, v), f(1, v), f(2, v)]))
+                        .fold(0.0, f64::max),
+                humidity: scale
+                    * (0..3)
+                        .map(|u| second_difference([f(u, 0), f(u, 1), f(u, 2)]))
+                        .fold(0.0, f64::max),
+                // hT.hH/4 |f_TH|, with f_TH from the corners of each quarter cell.
+                mixed: scale
+                    * (0..4)
+                        .map(|index| (index / 2, index % 2))
+                        .map(|(u, v)| f(u + 1, v + 1) - f(u + 1, v) - f(u, v + 1) + f(u, v))
+                        .fold(0.0, |largest, value| value.abs().max(largest)),
+            });
+        }
+        return Ok(bound);
+    }
+
+    // Largest error bound of any cell, term by term.
+    fn error_bound(&self) -> Result<ErrorBound, TableError> {
+        let mut bound = ErrorBound::default();
+        for i in 0..self.temperatures.steps - 1 {
+            for j in 0..self.humidities.steps - 1 {
+                bound = bound.max(self.cell_error_bound(i, j)?);
+            }
+        }
+        return Ok(bound);
+    }
+
+    // Bound on the relative error of any derived property, see ParametersTable.
+    pub fn max_relative_error(&self) -> f64 {
+        return self.error_bound.total();
+    }
+
+    fn node(&self, i: usize, j: usize) -> &PhysicalParameters {
+        return &self.nodes[i * self.humidities.steps + j];
+    }
+
+    fn interpolate(&self, i: usize, u: f64, j: usize, v: f64) -> PhysicalParameters {
+        return blend([
+            (self.node(i, j), (1.0 - u) * (1.0 - v)),
+            (self.node(i, j + 1), (1.0 - u) * v),
+            (self.node(i + 1, j), u * (1.0 - v)),
+            (self.node(i + 1, j + 1), u * v),
+        ]);
+    }
+
+    // Interpolated parameters, or None outside the table.
+    pub fn try_parameters(
+        &self,
+        temperature: f64,
+        humidity_saturation: f64,
+    ) -> Option<PhysicalParameters> {
+        let (i, u) = self.temperatures.locate(temperature)?;
+        let (j, v) = self.humidities.locate(humidity_saturation)?;
+        let mut parameters = self.interpolate(i, u, j, v);
+        parameters.pressure = self.node(i, j).pressure;
+        parameters.molar_co2 = self.node(i, j).molar_co2;
+        parameters.temperature = temperature;
+        parameters.humidity_saturation = humidity_saturation;
+        return Some(parameters);
+    }
+}
+
+impl ParametersProvider for ParametersTable {
+    // Falls back to the exact path outside the table.
+    fn parameters(&self, temperature: f64, humidity_saturation: f64) -> PhysicalParameters {
+        return self
+            .try_parameters(temperature, humidity_saturation)
+            .unwrap_or_else(|| {
+                ExactParameters::new(self.base).parameters(temperature, humidity_saturation)
+            });
+    }
+}
+
+#[cfg(test)]
+mod table_tests;
@@ -0,0 +1,96 @@
+#[cfg(test)]
+mod table_tests {
+    use crate::structs::parameters::ParametersBuilder;
+
+    use super::super::*;
+
+    fn sea_level() -> ParametersBuilder {
+        return ParametersBuilder::new()
+            .with_pressure(101.325)
+            .with_molar_co2(0.00039);
+    }
+
+    fn assert_within(expected: f64, actual: f64, relative_error: f64) {
+        assert!(
+            ((actual - expected) / expected).abs() <= relative_error,
+            "{} differs from {} by more than {}",
+            actual,
+            expected,
+            relative_error
+        );
+    }
+
+    #[test]
+    fn it_matches_the_reference_fixtures() {
+        let exact = ExactParameters::new(sea_level());
+        let table = ParametersTable::new(sea_level(), 268.15..=318.15, 41, 0.0..=100.0, 5).unwrap();
+        assert!(table.max_relative_error() < 0.01);
+
+        // (temperature, humidity, sound speed, air density) from parameters_tests.
+        let fixtures = [
+            (273.15, 0.0, 331.43495349518037, 1.2931565359886497),
+            (293.15, 0.0, 343.2878525006776, 1.2051536496637991),
+            (293.15, 100.0, 348.3266788996193, 1.2050288905087325),
+        ];
+        for (temperature, humidity_saturation, sound_speed, air_density) in fixtures {
+            let parameters = exact.parameters(temperature, humidity_saturation);
+            assert_eq!(sound_speed, parameters.sound_speed);
+            assert_eq!(air_density, parameters.air_density);
+
+            let parameters = table.parameters(temperature, humidity_saturation);
+            assert_within(
+                sound_speed,
+                parameters.sound_speed,
+                table.max_relative_error(),
+            );
+            assert_within(
+                air_density,
+                parameters.air_density,
+                table.max_relative_error(),
+            );
+        }
+    }
+
+    #[test]
+    fn it_stays_within_the_error_bound() {
+        let exact = ExactParameters::new(sea_level());
+        let table =
+            ParametersTable::with_tolerance(sea_level(), 273.15..=313.15, 0.0..=100.0, 1e-4)
+                .unwrap();
+        assert!(table.max_relative_error() <= 1e-4);
+
+        for step in 0..=97 {
+            let temperature = 273.15 + 40.0 * step as f64 / 97.0;
+            let humidity_saturation = (step * 37 % 101) as f64;
+            let expected = exact.parameters(temperature, humidity_saturation);
+            let actual = table.parameters(temperature, humidity_saturation);
+            assert_eq!(temperature, actual.temperature);
+            for (actual, expected) in [
+                (actual.sound_speed, expected.sound_speed),
+                (actual.air_density, expected.air_density),
+                (actual.alpha_constant, expected.alpha_constant),
+                (actual.prandtl_number, expected.prandtl_number),
+            ] {
+                assert_within(expected, actual, table.max_relative_error());
+            }
+        }
+    }
+
+    #[test]
+    fn it_falls_back_to_the_exact_path_outside_the_table() {
+        let table = ParametersTable::new(sea_level(), 283.15..=303.15, 3, 0.0..=100.0, 3).unwrap();
+        assert!(table.try_parameters(310.15, 50.0).is_none());
+        let expected = ExactParameters::new(sea_level()).parameters(310.15, 50.0);
+        assert_eq!(expected, table.parameters(310.15, 50.0));
+    }
+
+    #[test]
+    fn it_rejects_invalid_grids() {
+        let actual = ParametersTable::new(sea_level(), 300.0..=290.0, 3, 0.0..=100.0, 3);
+        assert_eq!(Some(TableError::InvalidRange), actual.err());
+        let actual = ParametersTable::new(sea_level(), 290.0..=300.0, 1, 0.0..=100.0, 3);
+        assert_eq!(Some(TableError::TooFewSteps), actual.err());
+        let actual = ParametersTable::new(sea_level(), 290.0..=380.0, 3, 0.0..=100.0, 3);
+        assert!(matches!(actual.err(), Some(TableError::Parameters(_))));
+    }
+}
@@ -1,3 +1,6 @@
+use std::{error::Error, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum TemperatureType {
     F,
     C,
@@ -16,13 +19,151 @@ pub fn fahrenheit_to_celsius(degrees: f64) -> f64 {
     return (degrees + 40.) * 5. / 9. - 40.;
 }
 
-pub(crate) fn normalize_temperature(temperature: f64, temperature_type: TemperatureType) -> f64 {
+pub fn celsius_to_fahrenheit(degrees: f64) -> f64 {
+    return (degrees + 40.) * 9. / 5. - 40.;
+}
+
+pub fn fahrenheit_to_kelvin(degrees: f64) -> f64 {
+    return celsius_to_kelvin(fahrenheit_to_celsius(degrees));
+}
+
+pub fn kelvin_to_fahrenheit(degrees: f64) -> f64 {
+    return celsius_to_fahrenheit(kelvin_to_celsius(degrees));
+}
+
+// Temperature in Kelvin degrees.
+pub fn normalize_temperature(temperature: f64, temperature_type: TemperatureType) -> f64 {
     return match temperature_type {
-        TemperatureType::F => celsius_to_kelvin(fahrenheit_to_celsius(temperature)),
+        TemperatureType::F => fahrenheit_to_kelvin(temperature),
         TemperatureType::C => celsius_to_kelvin(temperature),
         TemperatureType::K => temperature.clamp(0., f64::INFINITY),
     };
 }
 
+// Temperature in Kelvin degrees expressed in another unit.
+pub fn denormalize_temperature(temperature: f64, temperature_type: TemperatureType) -> f64 {
+    return match temperature_type {
+        TemperatureType::F => kelvin_to_fahrenheit(temperature),
+        TemperatureType::C => kelvin_to_celsius(temperature),
+        TemperatureType::K => temperature.clamp(0., f64::INFINITY),
+    };
+}
+
+pub fn convert_temperature(temperature: f64, from: TemperatureType, to: TemperatureType) -> f64 {
+    if from == to {
+        return temperature;
+    }
+    return match (from, to) {
+        (TemperatureType::F, TemperatureType::C) => fahrenheit_to_celsius(temperature),
+        (TemperatureType::C, TemperatureType::F) => celsius_to_fahrenheit(temperature),
+        _ => denormalize_temperature(normalize_temperature(temperature, from), to),
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TemperatureParseError {
+    UnknownUnit(String),  // Unit text that is not F, C or K
+    InvalidValue(String), // Number text that cannot be parsed
+}
+
+impl fmt::Display for TemperatureParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TemperatureParseError::UnknownUnit(unit) => {
+                write!(f, "unknown temperature unit \"{}\"", unit)
+            }
+            TemperatureParseError::InvalidValue(value) => {
+                write!(f, "invalid temperature value \"{}\"", value)
+            }
+        };
+    }
+}
+
+impl Error for TemperatureParseError {}
+
+impl fmt::Display for TemperatureType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            TemperatureType::F => write!(f, "°F"),
+            TemperatureType::C => write!(f, "°C"),
+            TemperatureType::K => write!(f, "K"),
+        };
+    }
+}
+
+// Accepts the unit symbol with or without a degree sign, or its name, in any case.
+impl FromStr for TemperatureType {
+    type Err = TemperatureParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let unit = text.trim();
+        let symbol = unit.strip_prefix('°').unwrap_or(unit).trim_start();
+        return match symbol.to_lowercase().as_str() {
+            "f" | "fahrenheit" => Ok(TemperatureType::F),
+            "c" | "celsius" => Ok(TemperatureType::C),
+            "k" | "kelvin" => Ok(TemperatureType::K),
+            _ => Err(TemperatureParseError::UnknownUnit(unit.to_string())),
+        };
+    }
+}
+
+// Temperature value in the unit it was entered in, for display back to the user.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    pub value: f64,
+    pub unit: TemperatureType,
+}
+
+impl Temperature {
+    pub fn new(value: f64, unit: TemperatureType) -> Self {
+        return Self { value, unit };
+    }
+
+    pub fn from_kelvin(temperature: f64, unit: TemperatureType) -> Self {
+        return Self::new(denormalize_temperature(temperature, unit), unit);
+    }
+
+    pub fn kelvin(&self) -> f64 {
+        return normalize_temperature(self.value, self.unit);
+    }
+
+    pub fn value_in(&self, unit: TemperatureType) -> f64 {
+        return convert_temperature(self.value, self.unit, unit);
+    }
+
+    // Same temperature, displayed in another unit.
+    pub fn to_unit(&self, unit: TemperatureType) -> Self {
+        return Self::new(self.value_in(unit), unit);
+    }
+}
+
+// Uses the formatter precision for the value, as in "{:.1}" giving "22.5 °C".
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match f.precision() {
+            Some(precision) => write!(f, "{:.*} {}", precision, self.value, self.unit),
+            None => write!(f, "{} {}", self.value, self.unit),
+        };
+    }
+}
+
+// Parses a number followed by a unit, such as "72F", "22.5 °C" or "-3.5 celsius".
+impl FromStr for Temperature {
+    type Err = TemperatureParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let text = text.trim();
+        let split = text
+            .rfind(|character: char| character.is_ascii_digit() || character == '.')
+            .map_or(0, |index| index + 1);
+        let (value, unit) = text.split_at(split);
+        let value = value
+            .trim()
+            .parse::<f64>()
+            .map_err(|_| TemperatureParseError::InvalidValue(value.trim().to_string()))?;
+        return Ok(Self::new(value, unit.parse()?));
+    }
+}
+
 #[cfg(test)]
 mod temperature_tests;
@@ -23,4 +23,81 @@ mod temperature_tests {
         let actual = normalize_temperature(f64::NAN, TemperatureType::C);
         assert!(f64::is_nan(actual));
     }
+
+    #[test]
+    fn it_converts_between_every_unit() {
+        let units = [TemperatureType::F, TemperatureType::C, TemperatureType::K];
+        let values = [
+            (-40.0, -40.0, 233.15),
+            (68.0, 20.0, 293.15),
+            (212.0, 100.0, 373.15),
+        ];
+        for (fahrenheit, celsius, kelvin) in values {
+            let by_unit = [fahrenheit, celsius, kelvin];
+            for (from, value) in units.iter().zip(by_unit) {
+                for (to, expected) in units.iter().zip(by_unit) {
+                    let actual = convert_temperature(value, *from, *to);
+                    assert!((actual - expected).abs() < 1e-9);
+                }
+            }
+            assert!(
+                (denormalize_temperature(kelvin, TemperatureType::F) - fahrenheit).abs() < 1e-9
+            );
+            assert!((denormalize_temperature(kelvin, TemperatureType::C) - celsius).abs() < 1e-9);
+        }
+        assert_eq!(68., celsius_to_fahrenheit(20.));
+    }
+
+    #[test]
+    fn it_prints_and_parses_units() {
+        assert_eq!("°F", TemperatureType::F.to_string());
+        assert_eq!("K", TemperatureType::K.to_string());
+        for unit in [TemperatureType::F, TemperatureType::C, TemperatureType::K] {
+            assert_eq!(Ok(unit), unit.to_string().parse());
+        }
+        assert_eq!(Ok(TemperatureType::C), "celsius".parse());
+        assert_eq!(Ok(TemperatureType::F), "° f".parse());
+        assert_eq!(
+            Err(TemperatureParseError::UnknownUnit("R".to_string())),
+            "R".parse::<TemperatureType>()
+        );
+    }
+
+    #[test]
+    fn it_parses_temperatures() {
+        assert_eq!(Ok(Temperature::new(72., TemperatureType::F)), "72F".parse());
+        assert_eq!(
+            Ok(Temperature::new(22.5, TemperatureType::C)),
+            "22.5 °C".parse()
+        );
+        assert_eq!(
+            Ok(Temperature::new(-3.5, TemperatureType::C)),
+            " -3.5celsius ".parse()
+        );
+        assert_eq!(
+            Ok(Temperature::new(300., TemperatureType::K)),
+            "300 K".parse()
+        );
+        assert!(matches!(
+            "warm °C".parse::<Temperature>(),
+            Err(TemperatureParseError::InvalidValue(_))
+        ));
+        assert!(matches!(
+            "20".parse::<Temperature>(),
+            Err(TemperatureParseError::UnknownUnit(_))
+        ));
+    }
+
+    #[test]
+    fn it_remembers_the_entered_unit() {
+        let temperature: Temperature = "68 °F".parse().unwrap();
+        assert_eq!(293.15, temperature.kelvin());
+        assert_eq!("68 °F", temperature.to_string());
+
+        let celsius = temperature.to_unit(TemperatureType::C);
+        assert_eq!("20.0 °C", format!("{:.1}", celsius));
+        let kelvin = Temperature::from_kelvin(293.15, TemperatureType::F);
+        assert!((kelvin.value - 68.).abs() < 1e-9);
+        assert_eq!(TemperatureType::F, kelvin.unit);
+    }
 }
@@ -0,0 +1,210 @@
+use std::{collections::HashMap, error::Error, fmt};
+
+use num_complex::Complex64;
+use serde::{Deserialize, Serialize};
+
+use crate::structs::parameters::PhysicalParameters;
+
+use super::losses::{LossModel, ViscothermalLossModel};
+
+// Built-in bore materials, with the factor applied to smooth-wall boundary-layer losses.
+// The factors are rough starting values, to be calibrated against a prototype.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoreMaterial {
+    Smooth, // Ideal smooth wall, as assumed by the loss models
+    Brass,  // Drawn and polished metal tubing
+    Pvc,    // Extruded PVC pipe
+    Bamboo, // Natural bore, lightly sanded
+    Wood,   // Reamed and oiled hardwood
+    Pla,    // FDM-printed PLA with visible layer lines
+}
+
+impl BoreMaterial {
+    pub const ALL: [BoreMaterial; 6] = [
+        BoreMaterial::Smooth,
+        BoreMaterial::Brass,
+        BoreMaterial::Pvc,
+        BoreMaterial::Bamboo,
+        BoreMaterial::Wood,
+        BoreMaterial::Pla,
+    ];
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            BoreMaterial::Smooth => "smooth",
+            BoreMaterial::Brass => "brass",
+            BoreMaterial::Pvc => "pvc",
+            BoreMaterial::Bamboo => "bamboo",
+            BoreMaterial::Wood => "wood",
+            BoreMaterial::Pla => "pla",
+        };
+    }
+
+    pub fn loss_multiplier(self) -> f64 {
+        return match self {
+            BoreMaterial::Smooth => 1.0,
+            BoreMaterial::Brass => 1.02,
+            BoreMaterial::Pvc => 1.05,
+            BoreMaterial::Bamboo => 1.3,
+            BoreMaterial::Wood => 1.15,
+            BoreMaterial::Pla => 1.6,
+        };
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WallError {
+    InvalidMultiplier(f64), // Loss multipliers must be finite and positive
+}
+
+impl fmt::Display for WallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            WallError::InvalidMultiplier(value) => {
+                write!(f, "loss multiplier {} must be finite and positive", value)
+            }
+        };
+    }
+}
+
+impl Error for WallError {}
+
+fn check_multiplier(loss_multiplier: f64) -> Result<f64, WallError> {
+    if loss_multiplier <= 0.0 || !loss_multiplier.is_finite() {
+        return Err(WallError::InvalidMultiplier(loss_multiplier));
+    }
+    return Ok(loss_multiplier);
+}
+
+// Built-in and user-defined materials, looked up by name.
+#[derive(Debug, Clone)]
+pub struct MaterialRegistry {
+    materials: HashMap<String, f64>,
+}
+
+impl MaterialRegistry {
+    pub fn new() -> Self {
+        let materials = BoreMaterial::ALL
+            .iter()
+            .map(|material| (material.name().to_string(), material.loss_multiplier()))
+            .collect();
+        return Self { materials };
+    }
+
+    // Adds a material, or replaces the multiplier of an existing one.
+    pub fn register(&mut self, name: &str, loss_multiplier: f64) -> Result<(), WallError> {
+        self.materials
+            .insert(name.to_string(), check_multiplier(loss_multiplier)?);
+        return Ok(());
+    }
+
+    pub fn get(&self, name: &str) -> Option<f64> {
+        return self.materials.get(name).copied();
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.materials.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        return names;
+    }
+}
+
+impl Default for MaterialRegistry {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+// Loss model of a rough or porous wall: the losses of a smooth-wall model scaled by a
+// multiplier, which for Keefe 1984 amounts to scaling alpha_constant. The multiplier
+// also scales the dispersion, so rougher walls play both weaker and flatter.
+// Use one per bore section to give sections different materials.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "WallLossData")]
+pub struct WallLoss {
+    model: LossModel,     // Smooth-wall loss model
+    loss_multiplier: f64, // 1 for a smooth wall
+}
+
+// Wall loss as written in a design file, before validation.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct WallLossData {
+    model: LossModel,
+    loss_multiplier: f64,
+}
+
+impl WallLoss {
+    pub fn new(model: LossModel, loss_multiplier: f64) -> Result<Self, WallError> {
+        return Ok(Self {
+            model,
+            loss_multiplier: check_multiplier(loss_multiplier)?,
+        });
+    }
+
+    pub fn model(&self) -> LossModel {
+        return self.model;
+    }
+
+    pub fn loss_multiplier(&self) -> f64 {
+        return self.loss_multiplier;
+    }
+
+    pub fn from_material(model: LossModel, material: BoreMaterial) -> Self {
+        return Self {
+            model,
+            loss_multiplier: material.loss_multiplier(),
+        };
+    }
+}
+
+impl Default for WallLoss {
+    fn default() -> Self {
+        return Self::from_material(LossModel::default(), BoreMaterial::Smooth);
+    }
+}
+
+impl TryFrom<WallLossData> for WallLoss {
+    type Error = WallError;
+
+    fn try_from(data: WallLossData) -> Result<Self, Self::Error> {
+        return Self::new(data.model, data.loss_multiplier);
+    }
+}
+
+impl ViscothermalLossModel for WallLoss {
+    fn name(&self) -> &'static str {
+        return self.model.model().name();
+    }
+
+    fn propagation_constant(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        let lossless = Complex64::new(0.0, parameters.wave_number(frequency));
+        let smooth = self
+            .model
+            .model()
+            .propagation_constant(parameters, frequency, radius);
+        return lossless + self.loss_multiplier * (smooth - lossless);
+    }
+
+    fn characteristic_impedance(
+        &self,
+        parameters: &PhysicalParameters,
+        frequency: f64,
+        radius: f64,
+    ) -> Complex64 {
+        let lossless = Complex64::new(parameters.wave_impedance(radius), 0.0);
+        let smooth = self
+            .model
+            .model()
+            .characteristic_impedance(parameters, frequency, radius);
+        return lossless + self.loss_multiplier * (smooth - lossless);
+    }
+}
+
+#[cfg(test)]
+mod wall_tests;
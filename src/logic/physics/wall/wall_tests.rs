@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod wall_tests {
+    use crate::structs::parameters::{ParametersBuilder, PhysicalParameters};
+
+    use super::super::*;
+
+    fn scaled_alpha(parameters: &PhysicalParameters, factor: f64) -> PhysicalParameters {
+        let mut scaled = parameters.clone();
+        scaled.alpha_constant *= factor;
+        scaled.epsilon_constant *= factor;
+        return scaled;
+    }
+
+    #[test]
+    fn it_keeps_smooth_wall_losses_by_default() {
+        let parameters = ParametersBuilder::new().build();
+        let wall = WallLoss::default();
+        assert_eq!(
+            parameters.propagation_constant(440.0, 0.008),
+            wall.propagation_constant(&parameters, 440.0, 0.008)
+        );
+        assert_eq!(
+            parameters.characteristic_impedance(440.0, 0.008),
+            wall.characteristic_impedance(&parameters, 440.0, 0.008)
+        );
+    }
+
+    #[test]
+    fn it_scales_the_alpha_constant() {
+        let parameters = ParametersBuilder::new().build();
+        let wall = WallLoss::from_material(LossModel::Keefe1984, BoreMaterial::Pla);
+        let scaled = scaled_alpha(&parameters, BoreMaterial::Pla.loss_multiplier());
+
+        let expected = scaled.propagation_constant(440.0, 0.008);
+        let actual = wall.propagation_constant(&parameters, 440.0, 0.008);
+        assert!((actual - expected).norm() / expected.norm() < 1e-12);
+        let expected = scaled.characteristic_impedance(440.0, 0.008);
+        let actual = wall.characteristic_impedance(&parameters, 440.0, 0.008);
+        assert!((actual - expected).norm() / expected.norm() < 1e-12);
+    }
+
+    #[test]
+    fn it_makes_rough_walls_weaker_and_flatter() {
+        let parameters = ParametersBuilder::new().build();
+        let smooth = WallLoss::from_material(LossModel::ZwikkerKosten, BoreMaterial::Smooth);
+        let printed = WallLoss::from_material(LossModel::ZwikkerKosten, BoreMaterial::Pla);
+        let smooth = smooth.propagation_constant(&parameters, 440.0, 0.008);
+        let printed = printed.propagation_constant(&parameters, 440.0, 0.008);
+        assert!(printed.re > smooth.re);
+        assert!(printed.im > smooth.im);
+    }
+
+    #[test]
+    fn it_registers_user_materials() {
+        let mut registry = MaterialRegistry::new();
+        assert_eq!(Some(1.3), registry.get("bamboo"));
+        assert!(registry.get("resin").is_none());
+
+        registry.register("resin", 1.2).unwrap();
+        registry.register("bamboo", 1.4).unwrap();
+        assert_eq!(Some(1.2), registry.get("resin"));
+        assert_eq!(Some(1.4), registry.get("bamboo"));
+        assert_eq!(7, registry.names().len());
+
+        assert_eq!(
+            Err(WallError::InvalidMultiplier(0.0)),
+            registry.register("void", 0.0)
+        );
+        assert!(WallLoss::new(LossModel::Keefe1984, f64::NAN).is_err());
+    }
+
+    #[test]
+    fn it_serializes_section_losses() {
+        let wall = WallLoss::new(LossModel::ZwikkerKosten, 1.25).unwrap();
+        let json = serde_json::to_string(&wall).unwrap();
+        assert_eq!(r#"{"model":"zwikker_kosten","loss_multiplier":1.25}"#, json);
+        assert_eq!(wall, serde_json::from_str::<WallLoss>(&json).unwrap());
+        assert_eq!(1.25, wall.loss_multiplier());
+        assert_eq!(LossModel::ZwikkerKosten, wall.model());
+    }
+
+    #[test]
+    fn it_rejects_invalid_serialized_multipliers() {
+        for multiplier in ["-1.0", "0.0"] {
+            let json = format!(
+                r#"{{"model":"keefe1984","loss_multiplier":{}}}"#,
+                multiplier
+            );
+            assert!(serde_json::from_str::<WallLoss>(&json).is_err());
+        }
+        let toml = "model = \"keefe1984\"\nloss_multiplier = nan\n";
+        let error = toml::from_str::<WallLoss>(toml).unwrap_err();
+        assert!(error.to_string().contains("must be finite and positive"));
+    }
+}
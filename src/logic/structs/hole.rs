@@ -1,10 +1,193 @@
-#[derive(Debug)]
-pub(crate) struct Hole {
+use std::{error::Error, fmt};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HoleError {
+    MissingPosition,
+    MissingDiameter,
+    MissingHeight,
+    InvalidPosition(f64),  // Non-finite position
+    InvalidDiameter(f64),  // Non-finite or non-positive diameter
+    InvalidHeight(f64),    // Non-finite or non-positive chimney height
+    InvalidCurvature(f64), // Negative, or larger than the hole radius or the height
+}
+
+impl fmt::Display for HoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            HoleError::MissingPosition => write!(f, "hole position is not set"),
+            HoleError::MissingDiameter => write!(f, "hole diameter is not set"),
+            HoleError::MissingHeight => write!(f, "hole height is not set"),
+            HoleError::InvalidPosition(value) => write!(f, "hole position {} is invalid", value),
+            HoleError::InvalidDiameter(value) => {
+                write!(f, "hole diameter {} must be finite and positive", value)
+            }
+            HoleError::InvalidHeight(value) => {
+                write!(f, "hole height {} must be finite and positive", value)
+            }
+            HoleError::InvalidCurvature(value) => write!(
+                f,
+                "inner curvature radius {} must be between 0 and the hole radius and height",
+                value
+            ),
+        };
+    }
+}
+
+impl Error for HoleError {}
+
+// Tone hole drilled through the wall of the bore. Lengths are in the unit of the
+// instrument it belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "HoleBuilder")]
+pub struct Hole {
+    name: String,
+    position: f64,               // Distance of the hole axis from the top of the bore
+    diameter: f64,               // Diameter of the chimney
+    height: f64,                 // Chimney height, the wall thickness at the hole axis
+    inner_curvature_radius: f64, // Rounding of the edge where the chimney meets the bore
+}
+
+impl Hole {
+    // Hole with a sharp inner edge.
+    pub fn new(name: &str, position: f64, diameter: f64, height: f64) -> Result<Self, HoleError> {
+        return Self::builder(name)
+            .with_position(position)
+            .with_diameter(diameter)
+            .with_height(height)
+            .build();
+    }
+
+    pub fn builder(name: &str) -> HoleBuilder {
+        return HoleBuilder::new(name);
+    }
+
+    pub fn name(&self) -> &str {
+        return &self.name;
+    }
+
+    pub fn position(&self) -> f64 {
+        return self.position;
+    }
+
+    pub fn diameter(&self) -> f64 {
+        return self.diameter;
+    }
+
+    pub fn radius(&self) -> f64 {
+        return self.diameter / 2.0;
+    }
+
+    pub fn height(&self) -> f64 {
+        return self.height;
+    }
+
+    pub fn inner_curvature_radius(&self) -> f64 {
+        return self.inner_curvature_radius;
+    }
+
+    // Length added to the chimney by the volume where the hole meets a bore of a given
+    // radius, from Keefe 1990 and Nederveen 1998: tm = b/8 . b/a . (1 + 0.207 (b/a)^3).
+    pub fn matching_length(&self, bore_radius: f64) -> f64 {
+        let ratio = self.radius() / bore_radius;
+        return self.radius() / 8.0 * ratio * (1.0 + 0.207 * ratio.powi(3));
+    }
+
+    // Geometric chimney height plus the matching length. Radiation and inner length
+    // corrections of open and closed holes are left to the tone hole models.
+    pub fn effective_chimney_length(&self, bore_radius: f64) -> f64 {
+        return self.height + self.matching_length(bore_radius);
+    }
+
+    // Same hole with every dimension multiplied by a factor, for unit conversions.
+    pub(crate) fn scaled(&self, factor: f64) -> Self {
+        return Self {
+            name: self.name.clone(),
+            position: self.position * factor,
+            diameter: self.diameter * factor,
+            height: self.height * factor,
+            inner_curvature_radius: self.inner_curvature_radius * factor,
+        };
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct HoleBuilder {
     name: String,
-    position: f64,
-    diameter: f64,
-    height: f64,
+    position: Option<f64>,
+    diameter: Option<f64>,
+    height: Option<f64>,
+    #[serde(default)]
     inner_curvature_radius: f64,
 }
 
-impl Hole {}
+impl HoleBuilder {
+    pub fn new(name: &str) -> Self {
+        return Self {
+            name: name.to_string(),
+            position: None,
+            diameter: None,
+            height: None,
+            inner_curvature_radius: 0.0,
+        };
+    }
+
+    pub fn with_position(mut self, position: f64) -> Self {
+        self.position = Some(position);
+        return self;
+    }
+
+    pub fn with_diameter(mut self, diameter: f64) -> Self {
+        self.diameter = Some(diameter);
+        return self;
+    }
+
+    pub fn with_height(mut self, height: f64) -> Self {
+        self.height = Some(height);
+        return self;
+    }
+
+    pub fn with_inner_curvature_radius(mut self, inner_curvature_radius: f64) -> Self {
+        self.inner_curvature_radius = inner_curvature_radius;
+        return self;
+    }
+
+    pub fn build(self) -> Result<Hole, HoleError> {
+        let position = self.position.ok_or(HoleError::MissingPosition)?;
+        let diameter = self.diameter.ok_or(HoleError::MissingDiameter)?;
+        let height = self.height.ok_or(HoleError::MissingHeight)?;
+        if !position.is_finite() {
+            return Err(HoleError::InvalidPosition(position));
+        }
+        if diameter <= 0.0 || !diameter.is_finite() {
+            return Err(HoleError::InvalidDiameter(diameter));
+        }
+        if height <= 0.0 || !height.is_finite() {
+            return Err(HoleError::InvalidHeight(height));
+        }
+        let curvature = self.inner_curvature_radius;
+        if !(0.0..=(diameter / 2.0).min(height)).contains(&curvature) {
+            return Err(HoleError::InvalidCurvature(curvature));
+        }
+        return Ok(Hole {
+            name: self.name,
+            position,
+            diameter,
+            height,
+            inner_curvature_radius: curvature,
+        });
+    }
+}
+
+impl TryFrom<HoleBuilder> for Hole {
+    type Error = HoleError;
+
+    fn try_from(builder: HoleBuilder) -> Result<Self, Self::Error> {
+        return builder.build();
+    }
+}
+
+#[cfg(test)]
+mod hole_tests;
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod hole_tests {
+    use super::super::*;
+
+    #[test]
+    fn it_builds_holes() {
+        let hole = Hole::builder("T1")
+            .with_position(0.31)
+            .with_diameter(0.008)
+            .with_height(0.004)
+            .with_inner_curvature_radius(0.0005)
+            .build()
+            .unwrap();
+        assert_eq!("T1", hole.name());
+        assert_eq!(0.31, hole.position());
+        assert_eq!(0.004, hole.radius());
+        assert_eq!(0.004, hole.height());
+        assert_eq!(0.0005, hole.inner_curvature_radius());
+
+        let sharp = Hole::new("T1", 0.31, 0.008, 0.004).unwrap();
+        assert_eq!(0.0, sharp.inner_curvature_radius());
+    }
+
+    #[test]
+    fn it_validates_the_geometry() {
+        let builder = Hole::builder("T1").with_position(0.31);
+        assert_eq!(Err(HoleError::MissingDiameter), builder.clone().build());
+        assert_eq!(
+            Err(HoleError::MissingPosition),
+            Hole::builder("T1")
+                .with_diameter(0.008)
+                .with_height(0.004)
+                .build()
+        );
+        assert_eq!(
+            Err(HoleError::InvalidDiameter(0.0)),
+            Hole::new("T1", 0.31, 0.0, 0.004)
+        );
+        assert_eq!(
+            Err(HoleError::InvalidHeight(-0.004)),
+            Hole::new("T1", 0.31, 0.008, -0.004)
+        );
+        assert!(matches!(
+            Hole::new("T1", f64::NAN, 0.008, 0.004),
+            Err(HoleError::InvalidPosition(_))
+        ));
+        let curved = builder.with_diameter(0.008).with_height(0.004);
+        assert_eq!(
+            Err(HoleError::InvalidCurvature(0.005)),
+            curved.clone().with_inner_curvature_radius(0.005).build()
+        );
+        assert_eq!(
+            Err(HoleError::InvalidCurvature(-0.001)),
+            curved.with_inner_curvature_radius(-0.001).build()
+        );
+    }
+
+    #[test]
+    fn it_adds_the_matching_volume_to_the_chimney() {
+        let hole = Hole::new("T1", 0.31, 0.008, 0.004).unwrap();
+        // Same radius as the bore: b/8 * 1.207.
+        assert!((hole.matching_length(0.004) - 0.004 / 8.0 * 1.207).abs() < 1e-15);
+        let length = hole.effective_chimney_length(0.0095);
+        assert!(length > 0.004 && length < 0.0045);
+        assert!(hole.effective_chimney_length(0.02) < length);
+    }
+
+    #[test]
+    fn it_validates_when_deserialized() {
+        let json = r#"{ "name": "T1", "position": 0.31, "diameter": 0.008, "height": 0.004 }"#;
+        let hole: Hole = serde_json::from_str(json).unwrap();
+        assert_eq!(Hole::new("T1", 0.31, 0.008, 0.004).unwrap(), hole);
+        let json = serde_json::to_string(&hole).unwrap();
+        assert_eq!(hole, serde_json::from_str(&json).unwrap());
+
+        let json = r#"{ "name": "T1", "position": 0.31, "diameter": -0.008, "height": 0.004 }"#;
+        assert!(serde_json::from_str::<Hole>(json).is_err());
+    }
+}
@@ -1 +1 @@
-
+pub mod hole;
@@ -0,0 +1,166 @@
+use std::{error::Error, fmt};
+
+use crate::logic::physics::temperature::{normalize_temperature, TemperatureType};
+
+use super::parameters::{ParameterError, ParametersBuilder, PhysicalParameters};
+
+// Air conditions at one axial position along the bore.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProfilePoint {
+    pub position: f64,                    // Distance from the top of the bore in m
+    pub temperature: f64,                 // Temperature in Kelvin degrees
+    pub humidity_saturation: Option<f64>, // % of saturation, or the base humidity if None
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProfileError {
+    NoPoints,
+    NonFinitePoint(usize),    // Index of the point with a NaN or infinite value
+    UnorderedPosition(usize), // Index of the first point not after its predecessor
+}
+
+impl fmt::Display for ProfileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ProfileError::NoPoints => write!(f, "an air profile needs at least one point"),
+            ProfileError::NonFinitePoint(index) => {
+                write!(f, "profile point {} has a non-finite value", index)
+            }
+            ProfileError::UnorderedPosition(index) => write!(
+                f,
+                "profile point {} is not further along the bore than the previous one",
+                index
+            ),
+        };
+    }
+}
+
+impl Error for ProfileError {}
+
+// Position-dependent air along the bore. Temperature and humidity are interpolated
+// linearly between points and held constant beyond the first and last points;
+// pressure, CO2 and any humidity not given by the points come from the base builder.
+// A point without humidity takes the base humidity at its own temperature, so the
+// profile stays continuous next to points that give one.
+#[derive(Clone)]
+pub struct AirProfile {
+    base: ParametersBuilder,
+    points: Vec<ProfilePoint>,
+}
+
+impl AirProfile {
+    pub fn uniform(base: ParametersBuilder) -> Self {
+        return Self {
+            base,
+            points: vec![],
+        };
+    }
+
+    // Temperature falling linearly from the mouthpiece to the foot of a bore of length in m.
+    pub fn linear(
+        base: ParametersBuilder,
+        length: f64,
+        mouthpiece_temperature: f64,
+        foot_temperature: f64,
+        temperature_type: TemperatureType,
+    ) -> Result<Self, ProfileError> {
+        let mouthpiece = normalize_temperature(mouthpiece_temperature, temperature_type);
+        let foot = normalize_temperature(foot_temperature, temperature_type);
+        return Self::from_points(
+            base,
+            vec![
+                ProfilePoint {
+                    position: 0.0,
+                    temperature: mouthpiece,
+                    humidity_saturation: None,
+                },
+                ProfilePoint {
+                    position: length,
+                    temperature: foot,
+                    humidity_saturation: None,
+                },
+            ],
+        );
+    }
+
+    pub fn from_points(
+        base: ParametersBuilder,
+        points: Vec<ProfilePoint>,
+    ) -> Result<Self, ProfileError> {
+        if points.is_empty() {
+            return Err(ProfileError::NoPoints);
+        }
+        for (index, point) in points.iter().enumerate() {
+            let humidity_is_finite = point.humidity_saturation.is_none_or(f64::is_finite);
+            if !point.position.is_finite() || !point.temperature.is_finite() || !humidity_is_finite
+            {
+                return Err(ProfileError::NonFinitePoint(index));
+            }
+            if index > 0 && point.position <= points[index - 1].position {
+                return Err(ProfileError::UnorderedPosition(index));
+            }
+        }
+        return Ok(Self { base, points });
+    }
+
+    pub fn points(&self) -> &[ProfilePoint] {
+        return &self.points;
+    }
+
+    pub fn builder_at(&self, position: f64) -> ParametersBuilder {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return self.base,
+        };
+        if position <= first.position {
+            return self.apply(first.temperature, first.humidity_saturation);
+        }
+        if position >= last.position {
+            return self.apply(last.temperature, last.humidity_saturation);
+        }
+        let index = self
+            .points
+            .partition_point(|point| point.position <= position);
+        let (left, right) = (self.points[index - 1], self.points[index]);
+        let fraction = (position - left.position) / (right.position - left.position);
+        let temperature = left.temperature + fraction * (right.temperature - left.temperature);
+        let humidity_saturation = match (self.humidity_at(&left), self.humidity_at(&right)) {
+            (Some(left), Some(right)) => Some(left + fraction * (right - left)),
+            _ => None,
+        };
+        return self.apply(temperature, humidity_saturation);
+    }
+
+    pub fn parameters_at(&self, position: f64) -> PhysicalParameters {
+        return self.builder_at(position).build();
+    }
+
+    pub fn try_parameters_at(&self, position: f64) -> Result<PhysicalParameters, ParameterError> {
+        return self.builder_at(position).try_build();
+    }
+
+    // Air for a bore section, taken at its midpoint.
+    pub fn section_parameters(&self, start: f64, end: f64) -> PhysicalParameters {
+        return self.parameters_at(0.5 * (start + end));
+    }
+
+    // Humidity of a point, or the base humidity at its temperature if it has none.
+    fn humidity_at(&self, point: &ProfilePoint) -> Option<f64> {
+        return point.humidity_saturation.or_else(|| {
+            self.base
+                .with_temperature(point.temperature, TemperatureType::K)
+                .humidity_saturation()
+        });
+    }
+
+    fn apply(&self, temperature: f64, humidity_saturation: Option<f64>) -> ParametersBuilder {
+        let builder = self.base.with_temperature(temperature, TemperatureType::K);
+        return match humidity_saturation {
+            Some(humidity_saturation) => builder.with_humidity_saturation(humidity_saturation),
+            None => builder,
+        };
+    }
+}
+
+#[cfg(test)]
+mod air_profile_tests;
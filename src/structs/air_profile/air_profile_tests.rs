@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod air_profile_tests {
+    use super::super::*;
+
+    #[test]
+    fn it_interpolates_temperature_along_the_bore() {
+        let profile = AirProfile::linear(
+            ParametersBuilder::new(),
+            0.6,
+            35.0,
+            25.0,
+            TemperatureType::C,
+        )
+        .unwrap();
+
+        assert_eq!(308.15, profile.parameters_at(0.0).temperature);
+        assert!((profile.parameters_at(0.3).temperature - 303.15).abs() < 1e-9);
+        assert_eq!(298.15, profile.parameters_at(0.6).temperature);
+        assert_eq!(298.15, profile.parameters_at(1.0).temperature);
+        assert!((profile.section_parameters(0.0, 0.15).temperature - 306.90).abs() < 1e-9);
+
+        let top = profile.parameters_at(0.0);
+        let foot = profile.parameters_at(0.6);
+        assert!(top.sound_speed > foot.sound_speed);
+    }
+
+    #[test]
+    fn it_can_follow_a_user_defined_curve() {
+        let base = ParametersBuilder::new().with_humidity_saturation(40.0);
+        let profile = AirProfile::from_points(
+            base,
+            vec![
+                ProfilePoint {
+                    position: 0.0,
+                    temperature: 310.0,
+                    humidity_saturation: Some(100.0),
+                },
+                ProfilePoint {
+                    position: 0.1,
+                    temperature: 305.0,
+                    humidity_saturation: Some(80.0),
+                },
+                ProfilePoint {
+                    position: 0.5,
+                    temperature: 297.0,
+                    humidity_saturation: None,
+                },
+            ],
+        )
+        .unwrap();
+
+        let parameters = profile.parameters_at(0.05);
+        assert!((parameters.temperature - 307.5).abs() < 1e-9);
+        assert!((parameters.humidity_saturation - 90.0).abs() < 1e-9);
+        assert_eq!(40.0, profile.parameters_at(0.6).humidity_saturation);
+        // Towards a point without humidity, the humidity falls to the base value.
+        let parameters = profile.parameters_at(0.3);
+        assert!((parameters.humidity_saturation - 60.0).abs() < 1e-9);
+        let before = profile.parameters_at(0.4999).humidity_saturation;
+        assert!((before - 40.0).abs() < 0.01);
+
+        let profile = AirProfile::uniform(base);
+        assert_eq!(40.0, profile.parameters_at(0.3).humidity_saturation);
+    }
+
+    #[test]
+    fn it_rejects_invalid_points() {
+        let point = ProfilePoint {
+            position: 0.0,
+            temperature: 300.0,
+            humidity_saturation: None,
+        };
+        let base = ParametersBuilder::new();
+        assert_eq!(
+            Some(ProfileError::NoPoints),
+            AirProfile::from_points(base, vec![]).err()
+        );
+        assert_eq!(
+            Some(ProfileError::UnorderedPosition(1)),
+            AirProfile::from_points(base, vec![point, point]).err()
+        );
+        let nan = ProfilePoint {
+            position: f64::NAN,
+            ..point
+        };
+        assert_eq!(
+            Some(ProfileError::NonFinitePoint(0)),
+            AirProfile::from_points(base, vec![nan]).err()
+        );
+    }
+}
@@ -1 +1,4 @@
+pub mod air_profile;
 pub mod parameters;
+pub mod presets;
+pub mod warm_up;
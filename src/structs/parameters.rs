@@ -1,13 +1,17 @@
+use std::{error::Error, fmt, ops::RangeInclusive};
+
+use serde::{Deserialize, Serialize};
+
 use crate::logic::physics::{
-    calcs::{
-        calculate_air_density, calculate_alpha_constant, calculate_dynamic_viscosity,
-        calculate_epsilon_constant, calculate_molar_water_vapour, calculate_prandtl_number,
-        calculate_sound_speed, calculate_specific_heat, calculate_specific_heats_ratio,
-        calculate_thermal_conductivity, sound_speed_to_wave_number,
-    },
+    atmosphere::{pressure_at, pressure_at_from_sea_level},
+    calcs::AirState,
+    humidity::{normalize_humidity, HumidityType},
+    models::{AirModel, AirPropertyModel},
+    pressure::{normalize_pressure, PressureType},
     temperature::{normalize_temperature, TemperatureType},
 };
 
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PhysicalParameters {
     // Basic properties
     pub temperature: f64,         // Temperature in Kelvin degrees
@@ -28,12 +32,89 @@ pub struct PhysicalParameters {
     pub wave_number: f64,          // Wave number k at 1 Hz in rad/m
 }
 
-#[derive(Default, Clone, Copy)]
+// Inputs accepted by ParametersBuilder::try_build.
+// The CIPM-2007 density formula and its enhancement factor are specified for
+// 15-27 C and 60-110 kPa, and the Tsilingiris 2007 fits for cp, viscosity and
+// conductivity of moist air for 0-100 C. Values between these and the limits
+// below are extrapolated, and remain within a fraction of a percent for
+// sound speed; anything outside them is rejected as invalid.
+pub const TEMPERATURE_RANGE: RangeInclusive<f64> = 233.15..=373.15; // K, -40 to 100 C
+pub const PRESSURE_RANGE: RangeInclusive<f64> = 30.0..=120.0; // kPa, Everest summit to deep mines
+pub const HUMIDITY_SATURATION_RANGE: RangeInclusive<f64> = 0.0..=100.0; // %
+pub const MOLAR_CO2_RANGE: RangeInclusive<f64> = 0.0..=1.0; // mol/mol
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ParameterError {
+    MissingTemperature,
+    MissingPressure,
+    MissingHumiditySaturation,
+    MissingMolarCo2,
+    TemperatureOutOfRange(f64),        // Temperature in Kelvin degrees
+    PressureOutOfRange(f64),           // Air Pressure in kPa
+    HumiditySaturationOutOfRange(f64), // % of saturation
+    MolarCo2OutOfRange(f64),           // Molar fraction of CO2 in mol/mol
+    // Water vapour and CO2 together leave no room for air, e.g. saturated air above boiling point.
+    CompositionOutOfRange(f64), // Molar fraction of water vapour and CO2 in mol/mol
+}
+
+impl fmt::Display for ParameterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ParameterError::MissingTemperature => write!(f, "temperature is not set"),
+            ParameterError::MissingPressure => write!(f, "pressure is not set"),
+            ParameterError::MissingHumiditySaturation => write!(f, "humidity is not set"),
+            ParameterError::MissingMolarCo2 => write!(f, "CO2 fraction is not set"),
+            ParameterError::TemperatureOutOfRange(value) => write!(
+                f,
+                "temperature {} K is outside {} K to {} K",
+                value,
+                TEMPERATURE_RANGE.start(),
+                TEMPERATURE_RANGE.end()
+            ),
+            ParameterError::PressureOutOfRange(value) => write!(
+                f,
+                "pressure {} kPa is outside {} kPa to {} kPa",
+                value,
+                PRESSURE_RANGE.start(),
+                PRESSURE_RANGE.end()
+            ),
+            ParameterError::HumiditySaturationOutOfRange(value) => write!(
+                f,
+                "relative humidity {} % is outside {} % to {} %",
+                value,
+                HUMIDITY_SATURATION_RANGE.start(),
+                HUMIDITY_SATURATION_RANGE.end()
+            ),
+            ParameterError::MolarCo2OutOfRange(value) => write!(
+                f,
+                "CO2 fraction {} mol/mol is outside {} to {}",
+                value,
+                MOLAR_CO2_RANGE.start(),
+                MOLAR_CO2_RANGE.end()
+            ),
+            ParameterError::CompositionOutOfRange(value) => write!(
+                f,
+                "water vapour and CO2 make up {} mol/mol of the air, leaving no room for dry air",
+                value
+            ),
+        };
+    }
+}
+
+impl Error for ParameterError {}
+
+// Serialized with the inputs only; derived properties are recomputed by build.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ParametersBuilder {
-    temperature: Option<f64>,         // Temperature in Kelvin degrees
-    pressure: Option<f64>,            // Air Pressure in kPa
-    molar_co2: Option<f64>,           // Molar fraction of CO2 in mol/mol
-    humidity_saturation: Option<f64>, // % of saturation
+    temperature: Option<f64>, // Temperature in Kelvin degrees
+    pressure: Option<f64>,    // Air Pressure in kPa
+    molar_co2: Option<f64>,   // Molar fraction of CO2 in mol/mol
+    humidity: Option<f64>,    // Water vapour content, in humidity_type units
+    #[serde(default)]
+    humidity_type: HumidityType,
+    #[serde(default)]
+    model: AirModel, // Formula for the speed of sound
 }
 
 impl ParametersBuilder {
@@ -45,7 +126,8 @@ impl ParametersBuilder {
         return Self {
             temperature: Some(temperature),
             pressure: Some(pressure),
-            humidity_saturation: Some(humidity_saturation),
+            humidity: Some(humidity_saturation),
+            humidity_type: HumidityType::Saturation,
             molar_co2: Some(molar_co2),
             ..Default::default()
         };
@@ -56,11 +138,64 @@ impl ParametersBuilder {
         return self;
     }
 
+    pub fn with_pressure_in(mut self, pressure: f64, pressure_type: PressureType) -> Self {
+        self.pressure = Some(normalize_pressure(pressure, pressure_type));
+        return self;
+    }
+
+    // Sets the pressure of the ISA at an altitude in metres above sea level.
+    pub fn with_altitude(mut self, altitude: f64) -> Self {
+        self.pressure = Some(pressure_at(altitude));
+        return self;
+    }
+
+    // Sets the pressure at an altitude in metres, given the local sea-level pressure in kPa.
+    pub fn with_altitude_from_sea_level(mut self, altitude: f64, sea_level_pressure: f64) -> Self {
+        self.pressure = Some(pressure_at_from_sea_level(altitude, sea_level_pressure));
+        return self;
+    }
+
     pub fn with_humidity_saturation(mut self, humidity_saturation: f64) -> Self {
-        self.humidity_saturation = Some(humidity_saturation);
+        self.humidity = Some(humidity_saturation);
+        self.humidity_type = HumidityType::Saturation;
+        return self;
+    }
+
+    pub fn with_dew_point(mut self, dew_point: f64, temperature_type: TemperatureType) -> Self {
+        let dew_point = normalize_temperature(dew_point, temperature_type);
+        self.humidity = Some(dew_point);
+        self.humidity_type = HumidityType::DewPoint;
+        return self;
+    }
+
+    pub fn with_molar_water_vapour(mut self, molar_water_vapour: f64) -> Self {
+        self.humidity = Some(molar_water_vapour);
+        self.humidity_type = HumidityType::MolarWaterVapour;
+        return self;
+    }
+
+    // Absolute humidity in g/m^3.
+    pub fn with_absolute_humidity(mut self, absolute_humidity: f64) -> Self {
+        self.humidity = Some(absolute_humidity);
+        self.humidity_type = HumidityType::Absolute;
         return self;
     }
 
+    pub fn with_model(mut self, model: AirModel) -> Self {
+        self.model = model;
+        return self;
+    }
+
+    // Relative humidity equivalent to the humidity input, at the builder's temperature and pressure.
+    pub(crate) fn humidity_saturation(&self) -> Option<f64> {
+        return Some(normalize_humidity(
+            self.humidity?,
+            self.humidity_type,
+            self.pressure?,
+            self.temperature?,
+        ));
+    }
+
     pub fn with_molar_co2(mut self, molar_co2: f64) -> Self {
         self.molar_co2 = Some(molar_co2);
         return self;
@@ -71,82 +206,68 @@ impl ParametersBuilder {
         return self;
     }
 
+    // Builds the parameters after checking every input against its valid range.
+    pub fn try_build(self) -> Result<PhysicalParameters, ParameterError> {
+        let temperature = self.temperature.ok_or(ParameterError::MissingTemperature)?;
+        let pressure = self.pressure.ok_or(ParameterError::MissingPressure)?;
+        let humidity_saturation = self
+            .humidity_saturation()
+            .ok_or(ParameterError::MissingHumiditySaturation)?;
+        let molar_co2 = self.molar_co2.ok_or(ParameterError::MissingMolarCo2)?;
+
+        if !TEMPERATURE_RANGE.contains(&temperature) {
+            return Err(ParameterError::TemperatureOutOfRange(temperature));
+        }
+        if !PRESSURE_RANGE.contains(&pressure) {
+            return Err(ParameterError::PressureOutOfRange(pressure));
+        }
+        if !HUMIDITY_SATURATION_RANGE.contains(&humidity_saturation) {
+            return Err(ParameterError::HumiditySaturationOutOfRange(
+                humidity_saturation,
+            ));
+        }
+        if !MOLAR_CO2_RANGE.contains(&molar_co2) {
+            return Err(ParameterError::MolarCo2OutOfRange(molar_co2));
+        }
+        let parameters = self.build();
+        let molar_fraction = parameters.molar_water_vapour + parameters.molar_co2;
+        if molar_fraction >= 1.0 {
+            return Err(ParameterError::CompositionOutOfRange(molar_fraction));
+        }
+        return Ok(parameters);
+    }
+
+    // Builds the parameters without validation, panicking if an input is not set.
+    // Prefer try_build for values entered by users.
     pub fn build(self) -> PhysicalParameters {
+        return self.build_with(self.model.model());
+    }
+
+    // Same as build, with a sound speed model that is not one of the built-in AirModel.
+    pub fn build_with(self, model: &dyn AirPropertyModel) -> PhysicalParameters {
         let temperature = self.temperature.unwrap();
         let pressure = self.pressure.unwrap();
-        let humidity_saturation = self.humidity_saturation.unwrap();
+        let humidity_saturation = self.humidity_saturation().unwrap();
         let molar_co2 = self.molar_co2.unwrap();
+        let state =
+            AirState::new(pressure, temperature, humidity_saturation, molar_co2).with_model(model);
 
         return PhysicalParameters {
             pressure,
             molar_co2,
             temperature,
             humidity_saturation,
-            molar_water_vapour: calculate_molar_water_vapour(
-                pressure,
-                temperature,
-                humidity_saturation,
-            ),
-            air_density: calculate_air_density(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            dynamic_viscosity: calculate_dynamic_viscosity(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            specific_heat: calculate_specific_heat(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            specific_heats_ratio: calculate_specific_heats_ratio(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            thermal_conductivity: calculate_thermal_conductivity(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            prandtl_number: calculate_prandtl_number(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            sound_speed: calculate_sound_speed(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            epsilon_constant: calculate_epsilon_constant(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            alpha_constant: calculate_alpha_constant(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
-            wave_number: sound_speed_to_wave_number(
-                pressure,
-                temperature,
-                humidity_saturation,
-                molar_co2,
-            ),
+            molar_water_vapour: state.molar_water_vapour,
+            air_density: state.air_density,
+            dynamic_viscosity: state.dynamic_viscosity,
+            specific_heat: state.specific_heat,
+            specific_heats_ratio: state.specific_heats_ratio,
+            thermal_conductivity: state.thermal_conductivity,
+            prandtl_number: state.prandtl_number,
+            sound_speed: state.sound_speed,
+            epsilon_constant: state.epsilon_constant,
+            alpha_constant: state.alpha_constant,
+            wave_number: state.wave_number,
         };
     }
 }
@@ -0,0 +1,197 @@
+use std::{collections::HashMap, error::Error, fmt, fs, io, path::Path};
+
+use serde::Deserialize;
+
+use crate::logic::physics::temperature::TemperatureType;
+
+use super::parameters::{ParameterError, ParametersBuilder};
+
+#[derive(Clone, Copy)]
+pub enum AtmospherePreset {
+    Room,                 // 72 F, 45 % humidity, at sea level
+    DryAir,               // 20 C, dry, at sea level
+    SaturatedRoom,        // 20 C, saturated, at sea level
+    ExhaledBreath,        // 37 C, saturated, with 4 % CO2
+    WarmPlayedInstrument, // Bore air after a few minutes of playing, 30 C, saturated, 2.5 % CO2
+}
+
+impl AtmospherePreset {
+    pub const ALL: [AtmospherePreset; 5] = [
+        AtmospherePreset::Room,
+        AtmospherePreset::DryAir,
+        AtmospherePreset::SaturatedRoom,
+        AtmospherePreset::ExhaledBreath,
+        AtmospherePreset::WarmPlayedInstrument,
+    ];
+
+    pub fn name(self) -> &'static str {
+        return match self {
+            AtmospherePreset::Room => "room",
+            AtmospherePreset::DryAir => "dry_air",
+            AtmospherePreset::SaturatedRoom => "saturated_room",
+            AtmospherePreset::ExhaledBreath => "exhaled_breath",
+            AtmospherePreset::WarmPlayedInstrument => "warm_played_instrument",
+        };
+    }
+}
+
+impl ParametersBuilder {
+    pub fn from_preset(preset: AtmospherePreset) -> Self {
+        let builder = ParametersBuilder::new();
+        return match preset {
+            AtmospherePreset::Room => builder,
+            AtmospherePreset::DryAir => builder
+                .with_temperature(20.0, TemperatureType::C)
+                .with_humidity_saturation(0.0),
+            AtmospherePreset::SaturatedRoom => builder
+                .with_temperature(20.0, TemperatureType::C)
+                .with_humidity_saturation(100.0),
+            AtmospherePreset::ExhaledBreath => builder
+                .with_temperature(37.0, TemperatureType::C)
+                .with_humidity_saturation(100.0)
+                .with_molar_co2(0.04),
+            AtmospherePreset::WarmPlayedInstrument => builder
+                .with_temperature(30.0, TemperatureType::C)
+                .with_humidity_saturation(100.0)
+                .with_molar_co2(0.025),
+        };
+    }
+}
+
+// One preset as written in a presets file. Missing values are taken from the room preset.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PresetEntry {
+    temperature: Option<f64>,         // Temperature in Celsius degrees
+    pressure: Option<f64>,            // Air Pressure in kPa
+    altitude: Option<f64>,            // Altitude in m, used when no pressure is given
+    humidity_saturation: Option<f64>, // % of saturation
+    molar_co2: Option<f64>,           // Molar fraction of CO2 in mol/mol
+}
+
+impl PresetEntry {
+    fn to_builder(&self) -> ParametersBuilder {
+        let mut builder = ParametersBuilder::new();
+        if let Some(temperature) = self.temperature {
+            builder = builder.with_temperature(temperature, TemperatureType::C);
+        }
+        if let Some(altitude) = self.altitude {
+            builder = builder.with_altitude(altitude);
+        }
+        if let Some(pressure) = self.pressure {
+            builder = builder.with_pressure(pressure);
+        }
+        if let Some(humidity_saturation) = self.humidity_saturation {
+            builder = builder.with_humidity_saturation(humidity_saturation);
+        }
+        if let Some(molar_co2) = self.molar_co2 {
+            builder = builder.with_molar_co2(molar_co2);
+        }
+        return builder;
+    }
+}
+
+#[derive(Deserialize)]
+struct PresetFile {
+    #[serde(default)]
+    presets: HashMap<String, PresetEntry>,
+}
+
+#[derive(Debug)]
+pub enum PresetError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+    Invalid(String, ParameterError), // Preset name and the reason it was rejected
+}
+
+impl fmt::Display for PresetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            PresetError::Io(error) => write!(f, "cannot read presets file: {}", error),
+            PresetError::Parse(error) => write!(f, "cannot parse presets file: {}", error),
+            PresetError::Invalid(name, error) => write!(f, "preset \"{}\": {}", name, error),
+        };
+    }
+}
+
+impl Error for PresetError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        return match self {
+            PresetError::Io(error) => Some(error),
+            PresetError::Parse(error) => Some(error),
+            PresetError::Invalid(_, error) => Some(error),
+        };
+    }
+}
+
+// Built-in and team-defined atmospheres, looked up by name.
+//
+// Team presets are loaded from TOML files such as:
+//
+//     [presets.workshop]
+//     temperature = 23.5       # C
+//     altitude = 2240.0        # m, or pressure = 77.2 in kPa
+//     humidity_saturation = 35.0
+#[derive(Clone)]
+pub struct PresetRegistry {
+    presets: HashMap<String, ParametersBuilder>,
+}
+
+impl PresetRegistry {
+    pub fn new() -> Self {
+        let presets = AtmospherePreset::ALL
+            .iter()
+            .map(|preset| {
+                (
+                    preset.name().to_string(),
+                    ParametersBuilder::from_preset(*preset),
+                )
+            })
+            .collect();
+        return Self { presets };
+    }
+
+    pub fn register(&mut self, name: &str, builder: ParametersBuilder) {
+        self.presets.insert(name.to_string(), builder);
+    }
+
+    pub fn get(&self, name: &str) -> Option<ParametersBuilder> {
+        return self.presets.get(name).copied();
+    }
+
+    pub fn names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = self.presets.keys().map(String::as_str).collect();
+        names.sort_unstable();
+        return names;
+    }
+
+    // Registers every preset of a TOML document, replacing presets of the same name.
+    // Nothing is registered if any preset is invalid.
+    pub fn load_toml(&mut self, contents: &str) -> Result<(), PresetError> {
+        let file: PresetFile = toml::from_str(contents).map_err(PresetError::Parse)?;
+        let mut loaded = Vec::with_capacity(file.presets.len());
+        for (name, entry) in file.presets {
+            let builder = entry.to_builder();
+            if let Err(error) = builder.try_build() {
+                return Err(PresetError::Invalid(name, error));
+            }
+            loaded.push((name, builder));
+        }
+        self.presets.extend(loaded);
+        return Ok(());
+    }
+
+    pub fn load_file<P: AsRef<Path>>(&mut self, path: P) -> Result<(), PresetError> {
+        let contents = fs::read_to_string(path).map_err(PresetError::Io)?;
+        return self.load_toml(&contents);
+    }
+}
+
+impl Default for PresetRegistry {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(test)]
+mod presets_tests;
@@ -0,0 +1,74 @@
+#[cfg(test)]
+mod presets_tests {
+    use crate::structs::parameters::ParameterError;
+
+    use super::super::*;
+
+    #[test]
+    fn it_builds_the_reference_scenarios() {
+        let parameters = ParametersBuilder::from_preset(AtmospherePreset::ExhaledBreath).build();
+        assert_eq!(363.99110139436897, parameters.sound_speed);
+
+        let parameters = ParametersBuilder::from_preset(AtmospherePreset::SaturatedRoom).build();
+        assert_eq!(348.3266788996193, parameters.sound_speed);
+
+        let parameters = ParametersBuilder::from_preset(AtmospherePreset::DryAir).build();
+        assert_eq!(343.2878525006776, parameters.sound_speed);
+    }
+
+    #[test]
+    fn it_registers_presets_by_name() {
+        let mut registry = PresetRegistry::new();
+        assert!(registry.get("exhaled_breath").is_some());
+        assert!(registry.get("workshop").is_none());
+
+        registry.register("workshop", ParametersBuilder::new().with_pressure(80.0));
+        let parameters = registry.get("workshop").unwrap().build();
+        assert_eq!(80.0, parameters.pressure);
+        assert_eq!(6, registry.names().len());
+    }
+
+    #[test]
+    fn it_loads_presets_from_toml() {
+        let mut registry = PresetRegistry::new();
+        registry
+            .load_toml(
+                r#"
+                [presets.mountain_town]
+                temperature = 15.0
+                altitude = 2000.0
+                humidity_saturation = 30.0
+
+                [presets.sea_level_shop]
+                pressure = 102.0
+                "#,
+            )
+            .unwrap();
+
+        let parameters = registry.get("mountain_town").unwrap().build();
+        assert_eq!(288.15, parameters.temperature);
+        assert!((parameters.pressure - 79.495).abs() < 0.001);
+        assert_eq!(30.0, parameters.humidity_saturation);
+
+        let parameters = registry.get("sea_level_shop").unwrap().build();
+        assert_eq!(102.0, parameters.pressure);
+        assert_eq!(45.0, parameters.humidity_saturation);
+    }
+
+    #[test]
+    fn it_rejects_invalid_presets() {
+        let mut registry = PresetRegistry::new();
+        let actual = registry.load_toml("[presets.typo]\npressure = 1013.0\n");
+        assert!(matches!(
+            actual,
+            Err(PresetError::Invalid(
+                _,
+                ParameterError::PressureOutOfRange(_)
+            ))
+        ));
+        assert!(registry.get("typo").is_none());
+
+        let actual = registry.load_toml("[presets.typo]\npresure = 101.0\n");
+        assert!(matches!(actual, Err(PresetError::Parse(_))));
+    }
+}
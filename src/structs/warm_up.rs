@@ -0,0 +1,252 @@
+use std::{error::Error, fmt};
+
+use crate::logic::physics::{
+    calcs::calculate_molar_water_vapour,
+    humidity::molar_water_vapour_to_humidity_saturation,
+    sensitivity::CENTS_PER_OCTAVE,
+    temperature::{normalize_temperature, TemperatureType},
+};
+
+use super::parameters::{ParameterError, ParametersBuilder, PhysicalParameters};
+
+// Largest number of time steps of a simulation, a week of playing at 1 s steps.
+pub const MAX_WARM_UP_STEPS: usize = 604_800;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WarmUpError {
+    InvalidThermalModel, // Heat capacity, conductance or time constant not finite and positive
+    InvalidTimeStep,     // Time step or duration is not finite and positive
+    TooManySteps(f64),   // Number of time steps over MAX_WARM_UP_STEPS
+    Parameters(ParameterError),
+}
+
+impl fmt::Display for WarmUpError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            WarmUpError::InvalidThermalModel => write!(
+                f,
+                "heat capacity, conductances and time constants must be finite and positive"
+            ),
+            WarmUpError::InvalidTimeStep => {
+                write!(f, "time step and duration must be finite and positive")
+            }
+            WarmUpError::TooManySteps(steps) => write!(
+                f,
+                "{} time steps is more than the {} allowed",
+                steps, MAX_WARM_UP_STEPS
+            ),
+            WarmUpError::Parameters(error) => write!(f, "invalid air during warm-up: {}", error),
+        };
+    }
+}
+
+impl Error for WarmUpError {}
+
+// State of the bore air at one instant of the warm-up.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WarmUpSample {
+    pub time: f64,             // Seconds since playing started
+    pub wall_temperature: f64, // Temperature in Kelvin degrees
+    pub parameters: PhysicalParameters,
+    pub pitch_drift: f64, // Cents relative to the first sample, for a fixed bore
+}
+
+// Lumped model of the air in an instrument heating up while played.
+//
+// The bore air has a negligible heat capacity, so it settles at once between the
+// breath and the wall, weighted by their conductances; its CO2 mixes with the same
+// weights. The wall heats from the bore air and cools to the room, so its temperature
+// approaches a steady state exponentially. Pitch follows the speed of sound.
+//
+// The bore starts filled with room air, whose water vapour is replaced by the breath
+// exponentially, slowed by the dry wall taking up moisture. Any vapour over
+// saturation at the wall temperature condenses on the wall, which caps the content
+// until the wall warms up.
+#[derive(Debug, Clone, Copy)]
+pub struct WarmUp {
+    ambient: ParametersBuilder,
+    breath_temperature: f64,           // Temperature in Kelvin degrees
+    breath_humidity_saturation: f64,   // % of saturation
+    breath_molar_co2: f64,             // Molar fraction of CO2 in mol/mol
+    heat_capacity: f64,                // Heat capacity of the body in J/K
+    breath_conductance: f64,           // Heat flow from breath to bore air, W/K
+    wall_conductance: f64,             // Heat flow from bore air to the wall, W/K
+    ambient_conductance: f64,          // Heat flow from the wall to the room, W/K
+    humidification_time_constant: f64, // Time for the vapour to approach the breath's, s
+}
+
+impl WarmUp {
+    // Exhaled breath into a wooden flute of about 250 J/K, which warms up with a
+    // time constant of about five minutes and humidifies within about a minute.
+    pub fn new(ambient: ParametersBuilder) -> Self {
+        return Self {
+            ambient,
+            breath_temperature: normalize_temperature(37.0, TemperatureType::C),
+            breath_humidity_saturation: 100.0,
+            breath_molar_co2: 0.04,
+            heat_capacity: 250.0,
+            breath_conductance: 0.8,
+            wall_conductance: 0.8,
+            ambient_conductance: 0.4,
+            humidification_time_constant: 60.0,
+        };
+    }
+
+    pub fn with_breath(
+        mut self,
+        temperature: f64,
+        temperature_type: TemperatureType,
+        humidity_saturation: f64,
+        molar_co2: f64,
+    ) -> Self {
+        self.breath_temperature = normalize_temperature(temperature, temperature_type);
+        self.breath_humidity_saturation = humidity_saturation;
+        self.breath_molar_co2 = molar_co2;
+        return self;
+    }
+
+    pub fn with_heat_capacity(mut self, heat_capacity: f64) -> Self {
+        self.heat_capacity = heat_capacity;
+        return self;
+    }
+
+    pub fn with_conductances(mut self, breath: f64, wall: f64, ambient: f64) -> Self {
+        self.breath_conductance = breath;
+        self.wall_conductance = wall;
+        self.ambient_conductance = ambient;
+        return self;
+    }
+
+    pub fn with_humidification_time_constant(mut self, time_constant: f64) -> Self {
+        self.humidification_time_constant = time_constant;
+        return self;
+    }
+
+    fn check(&self) -> Result<(), WarmUpError> {
+        let values = [
+            self.heat_capacity,
+            self.breath_conductance,
+            self.wall_conductance,
+            self.ambient_conductance,
+            self.humidification_time_constant,
+        ];
+        if values
+            .iter()
+            .any(|value| *value <= 0.0 || !value.is_finite())
+        {
+            return Err(WarmUpError::InvalidThermalModel);
+        }
+        return Ok(());
+    }
+
+    // Share of the breath in the bore air, the rest coming from the wall.
+    fn breath_weight(&self) -> f64 {
+        return self.breath_conductance / (self.breath_conductance + self.wall_conductance);
+    }
+
+    // Conductance between breath and wall through the bore air, in W/K.
+    fn breath_to_wall_conductance(&self) -> f64 {
+        return self.breath_weight() * self.wall_conductance;
+    }
+
+    // Time constant of the wall temperature, in s.
+    pub fn time_constant(&self) -> f64 {
+        return self.heat_capacity / (self.breath_to_wall_conductance() + self.ambient_conductance);
+    }
+
+    fn steady_wall_temperature(&self, ambient_temperature: f64) -> f64 {
+        let breath = self.breath_to_wall_conductance();
+        return (breath * self.breath_temperature + self.ambient_conductance * ambient_temperature)
+            / (breath + self.ambient_conductance);
+    }
+
+    // Water vapour of the bore air at a time in s, in mol/mol, before condensation.
+    fn molar_water_vapour_at(
+        &self,
+        ambient: &PhysicalParameters,
+        breath: &PhysicalParameters,
+        time: f64,
+    ) -> f64 {
+        return breath.molar_water_vapour
+            + (ambient.molar_water_vapour - breath.molar_water_vapour)
+                * (-time / self.humidification_time_constant).exp();
+    }
+
+    // Bore air in equilibrium with a given wall temperature, at a time in s.
+    fn bore_air(
+        &self,
+        ambient: &PhysicalParameters,
+        breath: &PhysicalParameters,
+        wall_temperature: f64,
+        time: f64,
+    ) -> Result<PhysicalParameters, WarmUpError> {
+        let weight = self.breath_weight();
+        let mix = |breath: f64, other: f64| weight * breath + (1.0 - weight) * other;
+        let temperature = mix(self.breath_temperature, wall_temperature);
+        let saturated = calculate_molar_water_vapour(ambient.pressure, wall_temperature, 100.0);
+        let molar_water_vapour = self
+            .molar_water_vapour_at(ambient, breath, time)
+            .min(saturated);
+        let humidity_saturation = molar_water_vapour_to_humidity_saturation(
+            ambient.pressure,
+            temperature,
+            molar_water_vapour,
+        );
+        return self
+            .ambient
+            .with_temperature(temperature, TemperatureType::K)
+            .with_humidity_saturation(humidity_saturation.min(100.0))
+            .with_molar_co2(mix(self.breath_molar_co2, ambient.molar_co2))
+            .try_build()
+            .map_err(WarmUpError::Parameters);
+    }
+
+    // Samples every time step from the start of playing until the duration, in s.
+    pub fn simulate(
+        &self,
+        duration: f64,
+        time_step: f64,
+    ) -> Result<Vec<WarmUpSample>, WarmUpError> {
+        self.check()?;
+        if duration <= 0.0 || time_step <= 0.0 || !duration.is_finite() || !time_step.is_finite() {
+            return Err(WarmUpError::InvalidTimeStep);
+        }
+        let ambient = self.ambient.try_build().map_err(WarmUpError::Parameters)?;
+        let breath = self
+            .ambient
+            .with_temperature(self.breath_temperature, TemperatureType::K)
+            .with_humidity_saturation(self.breath_humidity_saturation)
+            .with_molar_co2(self.breath_molar_co2)
+            .try_build()
+            .map_err(WarmUpError::Parameters)?;
+        let steady = self.steady_wall_temperature(ambient.temperature);
+        let time_constant = self.time_constant();
+
+        let steps = (duration / time_step).round();
+        if steps > MAX_WARM_UP_STEPS as f64 {
+            return Err(WarmUpError::TooManySteps(steps));
+        }
+        let steps = steps as usize;
+        let mut samples: Vec<WarmUpSample> = Vec::with_capacity(steps + 1);
+        for step in 0..=steps {
+            let time = step as f64 * time_step;
+            let wall_temperature =
+                steady + (ambient.temperature - steady) * (-time / time_constant).exp();
+            let parameters = self.bore_air(&ambient, &breath, wall_temperature, time)?;
+            let initial_sound_speed = samples
+                .first()
+                .map_or(parameters.sound_speed, |first| first.parameters.sound_speed);
+            samples.push(WarmUpSample {
+                time,
+                wall_temperature,
+                pitch_drift: CENTS_PER_OCTAVE
+                    * (parameters.sound_speed / initial_sound_speed).log2(),
+                parameters,
+            });
+        }
+        return Ok(samples);
+    }
+}
+
+#[cfg(test)]
+mod warm_up_tests;
@@ -0,0 +1,131 @@
+#[cfg(test)]
+mod warm_up_tests {
+    use super::super::*;
+
+    fn room() -> ParametersBuilder {
+        return ParametersBuilder::new()
+            .with_temperature(20.0, TemperatureType::C)
+            .with_humidity_saturation(50.0);
+    }
+
+    #[test]
+    fn it_goes_sharp_while_warming_up() {
+        let samples = WarmUp::new(room()).simulate(600.0, 30.0).unwrap();
+        assert_eq!(21, samples.len());
+        assert_eq!(0.0, samples[0].pitch_drift);
+        assert_eq!(293.15, samples[0].wall_temperature);
+        for pair in samples.windows(2) {
+            assert!(pair[1].wall_temperature > pair[0].wall_temperature);
+            assert!(pair[1].parameters.temperature > pair[0].parameters.temperature);
+            assert!(pair[1].pitch_drift > pair[0].pitch_drift);
+            assert!(pair[1].parameters.molar_water_vapour > pair[0].parameters.molar_water_vapour);
+        }
+        // A few kelvin of warming and the humid breath make tens of cents of drift.
+        let drift = samples.last().unwrap().pitch_drift;
+        assert!(drift > 20.0 && drift < 60.0);
+    }
+
+    #[test]
+    fn it_settles_between_breath_and_room() {
+        let warm_up = WarmUp::new(room());
+        let samples = warm_up
+            .simulate(20.0 * warm_up.time_constant(), warm_up.time_constant())
+            .unwrap();
+        let last = samples.last().unwrap();
+        let first = &samples[0];
+        assert!(last.parameters.temperature < normalize_temperature(37.0, TemperatureType::C));
+        assert!(last.wall_temperature < last.parameters.temperature);
+        let previous = &samples[samples.len() - 2];
+        assert!((last.wall_temperature - previous.wall_temperature).abs() < 1e-6);
+        assert!(last.parameters.molar_co2 > first.parameters.molar_co2 * 0.99);
+        assert!(last.parameters.humidity_saturation <= 100.0);
+    }
+
+    #[test]
+    fn it_warms_light_instruments_faster() {
+        let wood = WarmUp::new(room());
+        let metal = wood.with_heat_capacity(60.0);
+        assert!(metal.time_constant() < wood.time_constant() / 4.0);
+        let wood = wood.simulate(120.0, 120.0).unwrap();
+        let metal = metal.simulate(120.0, 120.0).unwrap();
+        assert!(metal[1].pitch_drift > wood[1].pitch_drift);
+    }
+
+    #[test]
+    fn it_humidifies_from_room_air() {
+        let warm_room = room().with_temperature(30.0, TemperatureType::C);
+        let warm_up = WarmUp::new(warm_room)
+            .with_breath(37.0, TemperatureType::C, 50.0, 0.04)
+            .with_humidification_time_constant(120.0);
+        let samples = warm_up.simulate(240.0, 120.0).unwrap();
+        let ambient = warm_room.build().molar_water_vapour;
+        let breath = warm_room
+            .with_temperature(37.0, TemperatureType::C)
+            .build()
+            .molar_water_vapour;
+        assert!((samples[0].parameters.molar_water_vapour - ambient).abs() < 1e-12);
+        for (sample, decay) in samples
+            .iter()
+            .zip([1.0, (-1.0_f64).exp(), (-2.0_f64).exp()])
+        {
+            let expected = breath + (ambient - breath) * decay;
+            assert!((sample.parameters.molar_water_vapour - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn it_condenses_excess_water_vapour() {
+        let cold_room = room().with_temperature(5.0, TemperatureType::C);
+        let samples = WarmUp::new(cold_room).simulate(600.0, 60.0).unwrap();
+        for sample in &samples[1..] {
+            let saturated = cold_room
+                .with_temperature(sample.wall_temperature, TemperatureType::K)
+                .with_humidity_saturation(100.0)
+                .build()
+                .molar_water_vapour;
+            assert!((sample.parameters.molar_water_vapour - saturated).abs() < 1e-12);
+            // The bore air is warmer than the wall it condenses on.
+            assert!(sample.parameters.humidity_saturation < 100.0);
+        }
+    }
+
+    #[test]
+    fn it_rejects_invalid_models() {
+        let warm_up = WarmUp::new(room());
+        assert_eq!(
+            Err(WarmUpError::InvalidThermalModel),
+            warm_up.with_heat_capacity(0.0).simulate(60.0, 1.0)
+        );
+        assert_eq!(
+            Err(WarmUpError::InvalidTimeStep),
+            warm_up.simulate(60.0, f64::NAN)
+        );
+        assert_eq!(
+            Err(WarmUpError::InvalidThermalModel),
+            warm_up
+                .with_humidification_time_constant(-1.0)
+                .simulate(60.0, 1.0)
+        );
+        assert!(matches!(
+            warm_up.simulate(600.0, 1e-12),
+            Err(WarmUpError::TooManySteps(_))
+        ));
+        assert!(matches!(
+            warm_up.simulate(f64::MAX, 1e-300),
+            Err(WarmUpError::TooManySteps(_))
+        ));
+        assert_eq!(
+            MAX_WARM_UP_STEPS + 1,
+            warm_up
+                .simulate(MAX_WARM_UP_STEPS as f64, 1.0)
+                .unwrap()
+                .len()
+        );
+        assert!(matches!(
+            warm_up
+                .with_breath(120.0, TemperatureType::C, 100.0, 0.04)
+                .simulate(60.0, 1.0),
+            Err(WarmUpError::Parameters(_))
+        ));
+    }
+}